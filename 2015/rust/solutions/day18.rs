@@ -1,3 +1,4 @@
+use aoc_lib::cartesian::automaton::{self, Automaton};
 use aoc_lib::cartesian::Grid;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -5,28 +6,18 @@ use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 pub struct Solution18;
 impl Solution18 {
     fn parse(input: ProblemInput) -> Grid<bool> {
-        input.grid().map_elements(|c| *c == '#')
+        input.grid_of::<bool>()
     }
 
-    fn animation_step(state: Grid<bool>, broken_grid: bool) -> Grid<bool> {
-        let size = state.size;
-        let mut next_state = Grid::empty(size, false);
-        for (idx, value) in state.enumerate() {
-            let active_neighbors = idx.moore_neighbors(size).into_iter().filter(|n| *state.get(n)).count();
-            if *value {
-                next_state.set(&idx, active_neighbors == 2 || active_neighbors == 3);
-            } else {
-                next_state.set(&idx, active_neighbors == 3);
-            }
-        }
+    fn life(on: &bool, neighbors: &[&bool]) -> bool {
+        let active_neighbors = neighbors.iter().filter(|n| ***n).count();
+        active_neighbors == 3 || (*on && active_neighbors == 2)
+    }
 
-        if broken_grid {
-            for corner in next_state.corners() {
-                next_state.set(&corner, true);
-            }
+    fn light_corners(grid: &mut Grid<bool>) {
+        for corner in grid.corners() {
+            grid.set(&corner, true);
         }
-
-        next_state
     }
 }
 
@@ -43,19 +34,23 @@ impl Solution for Solution18 {
     fn solve_version01(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
         let initial_grid = Self::parse(input);
         let steps = if is_sample { 4 } else { 100 };
-        let final_grid = (0..steps).fold(initial_grid, |state, _| Self::animation_step(state, false));
+        let final_grid = Automaton::new(automaton::moore, Self::life).run(initial_grid, steps);
 
         final_grid.iter().filter(|c| **c).count().to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
         let mut initial_grid = Self::parse(input);
-        for corner in initial_grid.corners() {
-            initial_grid.set(&corner, true);
-        }
+        Self::light_corners(&mut initial_grid);
 
+        // Broken corners are always on
         let steps = if is_sample { 5 } else { 100 };
-        let final_grid = (0..steps).fold(initial_grid, |state, _| Self::animation_step(state, true));
+        let lights = Automaton::new(automaton::moore, Self::life);
+        let final_grid = (0..steps).fold(initial_grid, |state, _| {
+            let mut next_state = lights.step(&state);
+            Self::light_corners(&mut next_state);
+            next_state
+        });
 
         final_grid.iter().filter(|c| **c).count().to_result()
     }
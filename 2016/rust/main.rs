@@ -96,4 +96,10 @@ mod tests2016 {
     test_day!(day23);
     test_day!(day24);
     test_day!(day25);
+
+    mod generated {
+        use aoc_lib::test_generated;
+
+        test_generated!(day08, 0..100; version01);
+    }
 }
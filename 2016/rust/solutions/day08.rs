@@ -1,6 +1,11 @@
+use std::collections::HashSet;
+
 use aoc_lib::cartesian::{Grid, Index, Size};
+use aoc_lib::ocr;
+use aoc_lib::prelude::generator::{InputGenerator, SeededRng};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
+use aoc_lib::scan;
 use itertools::Itertools;
 
 #[derive(Debug)]
@@ -17,40 +22,17 @@ impl Solution08 {
             .lines()
             .into_iter()
             .map(|l| {
-                if l.starts_with("rect") {
-                    let size = l.strip_prefix("rect ").unwrap();
-                    let (w, h) = size.split_once('x').unwrap();
-                    Instruction::Rect(w.parse().unwrap(), h.parse().unwrap())
-                } else if l.starts_with("rotate column") {
-                    let vals = l.strip_prefix("rotate column ").unwrap();
-                    let (xs, l) = vals.split_once(" by ").unwrap();
-                    let (_, x) = xs.split_once('=').unwrap();
-                    Instruction::RotateColumn(x.parse().unwrap(), l.parse().unwrap())
-                } else if l.starts_with("rotate row") {
-                    let vals = l.strip_prefix("rotate row ").unwrap();
-                    let (ys, l) = vals.split_once(" by ").unwrap();
-                    let (_, y) = ys.split_once('=').unwrap();
-                    Instruction::RotateRow(y.parse().unwrap(), l.parse().unwrap())
-                } else {
-                    unreachable!()
-                }
+                scan!(l;
+                    "rect {}x{}" => Instruction::Rect(usize, usize),
+                    "rotate column x={} by {}" => Instruction::RotateColumn(usize, usize),
+                    "rotate row y={} by {}" => Instruction::RotateRow(usize, usize),
+                )
+                .unwrap()
             })
             .collect_vec()
     }
-}
 
-impl Solution for Solution08 {
-    fn results(&self) -> [ProblemResult; 4] {
-        [
-            ProblemResult::USize(6),
-            ProblemResult::USize(116),
-            ProblemResult::String("UPOJFLBCEZ".to_string()),
-            ProblemResult::String("UPOJFLBCEZ".to_string()),
-        ]
-    }
-
-    fn solve_version01(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
-        let instructions = Self::parse(input);
+    fn run(instructions: Vec<Instruction>, is_sample: bool) -> Grid<bool> {
         let mut screen = if is_sample {
             Grid::empty(Size::new(7, 3), false)
         } else {
@@ -67,26 +49,80 @@ impl Solution for Solution08 {
                     }
                 }
                 Instruction::RotateColumn(x, l) => {
-                    let length = screen.size.height;
-                    screen = screen.transpose();
-                    let col = screen.rows.get_mut(x).unwrap();
-                    *col = [&col[length - l..], &col[0..length - l]].concat();
-                    screen = screen.transpose();
-                }
-                Instruction::RotateRow(y, l) => {
-                    let length = screen.size.width;
-                    let row = screen.rows.get_mut(y).unwrap();
-                    *row = [&row[length - l..], &row[0..length - l]].concat();
+                    let mut col = screen.column(x).copied().collect_vec();
+                    col.rotate_right(l);
+                    screen.column_mut(x).zip(col).for_each(|(led, v)| *led = v);
                 }
+                Instruction::RotateRow(y, l) => screen.row_mut(y).rotate_right(l),
             }
         }
 
-        // screen.print(|_, led| if *led { '#' } else { '.' });
+        screen
+    }
+}
+
+impl InputGenerator for Solution08 {
+    fn generate(&self, rng: &mut SeededRng) -> Vec<String> {
+        (0..rng.range(1..100))
+            .map(|_| match rng.below(3) {
+                0 => format!("rect {}x{}", rng.range(1..51), rng.range(1..7)),
+                1 => format!("rotate column x={} by {}", rng.below(50), rng.range(1..6)),
+                _ => format!("rotate row y={} by {}", rng.below(6), rng.range(1..50)),
+            })
+            .collect()
+    }
+}
+
+impl Solution for Solution08 {
+    fn results(&self) -> [ProblemResult; 4] {
+        [
+            ProblemResult::USize(6),
+            ProblemResult::USize(116),
+            ProblemResult::NoSample,
+            ProblemResult::String("UPOJFLBCEZ".to_string()),
+        ]
+    }
+
+    fn solve_version01(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
+        let screen = Self::run(Self::parse(input), is_sample);
         screen.enumerate().filter(|(_, led)| **led).count().to_result()
     }
 
-    fn solve_version02(&self, _input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        // Run part 1 and print final screen.
-        String::from("UPOJFLBCEZ").to_result()
+    fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
+        // The sample screen is too small to show letters
+        if is_sample {
+            return ProblemResult::NoSample;
+        }
+
+        let screen = Self::run(Self::parse(input), is_sample);
+        ocr::read_grid(&screen).unwrap().to_result()
+    }
+
+    fn generator(&self) -> Option<&dyn InputGenerator> {
+        Some(self)
+    }
+
+    fn reference(&self, input: ProblemInput, version: u8) -> ProblemResult {
+        if version != 1 {
+            return ProblemResult::Unsolved;
+        }
+
+        // Track lit pixels only
+        let mut lit = HashSet::new();
+        for inst in Self::parse(input) {
+            lit = match inst {
+                Instruction::Rect(w, h) => lit.into_iter().chain((0..w).cartesian_product(0..h)).collect(),
+                Instruction::RotateColumn(x, l) => lit
+                    .into_iter()
+                    .map(|(i, j)| if i == x { (i, (j + l) % 6) } else { (i, j) })
+                    .collect(),
+                Instruction::RotateRow(y, l) => lit
+                    .into_iter()
+                    .map(|(i, j)| if j == y { ((i + l) % 50, j) } else { (i, j) })
+                    .collect(),
+            };
+        }
+
+        lit.len().to_result()
     }
 }
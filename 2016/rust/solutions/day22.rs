@@ -53,7 +53,7 @@ impl Solution22 {
         nodes.print(|idx, n| {
             if idx == (Index::new(0, 0)) {
                 "S"
-            } else if idx == (Index::new(nodes.size.width - 1, 0)) {
+            } else if idx == (Index::new(nodes.size().width - 1, 0)) {
                 "G"
             } else if n.used == 0 {
                 "_"
@@ -102,8 +102,7 @@ impl Solution for Solution22 {
         let smallest_node_size = nodes.iter().map(|n| n.size).min().unwrap();
         let empty_node = nodes.iter().find(|n| n.used == 0).unwrap().idx;
         let wall_row = nodes
-            .rows
-            .iter()
+            .rows()
             .find(|row| row.iter().any(|n| n.used > smallest_node_size))
             .unwrap();
         let best_spot = wall_row
@@ -115,10 +114,10 @@ impl Solution for Solution22 {
         // 2. Move empty node upwards through that spot into the top row just left of G.
         total_swaps += empty_node.i.abs_diff(best_spot.idx.i);
         total_swaps += empty_node.j;
-        total_swaps += (nodes.size.width - 2) - best_spot.idx.i;
+        total_swaps += (nodes.size().width - 2) - best_spot.idx.i;
 
         // 3. Repeat until G is in x = 1: Swap _ with G, then move empty node around to the left of G again (4 swaps)
-        let steps_left = nodes.size.width - 2;
+        let steps_left = nodes.size().width - 2;
         total_swaps += steps_left * (1 + 4);
 
         // 4. Single swap from G into S.
@@ -14,7 +14,7 @@ impl Solution24 {
         let mut found = HashMap::new();
 
         while let Some((pos, steps)) = queue.pop_front() {
-            for next_pos in pos.von_neumann_neighbors(map.size) {
+            for next_pos in pos.von_neumann_neighbors(map.size()) {
                 let tile = *map.get(&next_pos);
                 if tile != '#' && !seen.contains(&next_pos) {
                     seen.insert(next_pos);
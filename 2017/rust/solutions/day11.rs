@@ -1,12 +1,11 @@
-use aoc_lib::cartesian::{HexDirection, HexIndex};
+use aoc_lib::cartesian::hex::{Axial, HexOrientation};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-use itertools::Itertools;
 
 pub struct Solution11;
 impl Solution11 {
-    fn parse(input: ProblemInput) -> Vec<HexDirection> {
-        input.string().split(',').flat_map(|d| d.try_into()).collect_vec()
+    fn parse(input: ProblemInput) -> Vec<Axial> {
+        HexOrientation::FlatTop.parse_path(&input.string())
     }
 }
 
@@ -23,10 +22,10 @@ impl Solution for Solution11 {
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let path = Self::parse(input);
 
-        let start = HexIndex { x: 0, y: 0 };
-        let mut target = start.clone();
-        for dir in path {
-            target = target.step(&dir);
+        let start = Axial::zero();
+        let mut target = start;
+        for step in path {
+            target += step;
         }
 
         start.dist(&target).to_result()
@@ -35,11 +34,11 @@ impl Solution for Solution11 {
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let path = Self::parse(input);
 
-        let start = HexIndex { x: 0, y: 0 };
-        let mut target = start.clone();
+        let start = Axial::zero();
+        let mut target = start;
         let mut max_dist = 0;
-        for dir in path {
-            target = target.step(&dir);
+        for step in path {
+            target += step;
             max_dist = max_dist.max(start.dist(&target))
         }
 
@@ -1,5 +1,4 @@
 use aoc_lib::cartesian::Grid;
-use aoc_lib::graph::Graph;
 use aoc_lib::math::bits;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -42,21 +41,7 @@ impl Solution for Solution14 {
         }
 
         let grid = Grid::new(rows);
-        let graph: Graph<_> = grid
-            .enumerate()
-            .filter(|(_, used)| **used)
-            .map(|(idx, _)| {
-                (
-                    idx,
-                    idx.von_neumann_neighbors(grid.size)
-                        .into_iter()
-                        .filter(|n| *grid.get(n))
-                        .collect(),
-                )
-            })
-            .collect();
-
-        let components = graph.components();
-        components.len().to_result()
+        let regions = grid.regions_by(|used| *used);
+        regions.iter().filter(|(used, _)| *used).count().to_result()
     }
 }
@@ -5,7 +5,7 @@ use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 pub struct Solution19;
 impl Solution19 {
     fn follow_pipes(pipes: &Grid<char>) -> (String, u32) {
-        let start_i = pipes.rows[0].iter().position(|c| *c == '|').unwrap();
+        let start_i = pipes.row(0).iter().position(|c| *c == '|').unwrap();
 
         let mut letters = Vec::new();
         let mut steps = 0;
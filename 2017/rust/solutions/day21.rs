@@ -1,63 +1,44 @@
 use std::collections::HashMap;
 
-use aoc_lib::cartesian::Grid;
+use aoc_lib::cartesian::{Grid, Size};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-use itertools::{iproduct, Itertools};
 
 pub struct Solution21;
 impl Solution21 {
-    fn parse(input: ProblemInput) -> HashMap<String, String> {
+    fn parse(input: ProblemInput) -> HashMap<Grid<char>, Grid<char>> {
+        let to_grid = |s: &str| Grid::new(s.trim().split('/').map(|row| row.chars().collect()).collect());
         input
             .lines()
             .into_iter()
             .flat_map(|line| {
                 let (pattern, outcome_str) = line.split_once("=>").unwrap();
-                let outcome: String = outcome_str.trim().split('/').collect();
+                let outcome = to_grid(outcome_str);
 
                 // Every pattern has 8 rotations / flips, see dihedral group 4 (symmetry of a square)
-                let mut rules = Vec::new();
-                let grid = Grid::new(pattern.trim().split('/').map(|s| s.chars().collect()).collect());
-                for block in grid.symmetry_group() {
-                    rules.push((block.iter().collect::<String>(), outcome.clone()));
-                }
-
-                rules
+                to_grid(pattern).symmetry_group().map(|block| (block, outcome.clone()))
             })
             .collect()
     }
 
-    fn enhance(canvas: Vec<Vec<char>>, rules: &HashMap<String, String>) -> Vec<Vec<char>> {
-        let size = match (canvas.len() % 2, canvas.len() % 3) {
+    fn enhance(canvas: Grid<char>, rules: &HashMap<Grid<char>, Grid<char>>) -> Grid<char> {
+        let size = match (canvas.size().width % 2, canvas.size().width % 3) {
             (0, _) => 2,
             (_, 0) => 3,
             _ => unreachable!(),
         };
 
-        let blocks = canvas.len() / size;
-        let nsize = size + 1;
-        let mut new_canvas = vec![vec![' '; blocks * nsize]; blocks * nsize];
-        for bj in 0..blocks {
-            for bi in 0..blocks {
-                let block = iproduct!((bj * size..(bj + 1) * size), (bi * size..(bi + 1) * size));
-                let pattern = block.map(|(j, i)| canvas[j][i]).collect::<String>();
+        let blocks = canvas.split_into_tiles(Size::square(size));
+        Grid::from_tiles(blocks.map_elements(|block| rules[block].clone()))
+    }
 
-                let enhancement = rules[&pattern].chars();
-                let new_block = iproduct!((bj * nsize..(bj + 1) * nsize), (bi * nsize..(bi + 1) * nsize));
-                for (c, (j, i)) in enhancement.zip_eq(new_block) {
-                    new_canvas[j][i] = c;
-                }
-            }
+    fn run(rules: &HashMap<Grid<char>, Grid<char>>, iterations: usize) -> usize {
+        let mut canvas = Grid::new(vec![vec!['.', '#', '.'], vec!['.', '.', '#'], vec!['#', '#', '#']]);
+        for _ in 0..iterations {
+            canvas = Self::enhance(canvas, rules);
         }
 
-        new_canvas
-    }
-
-    fn count_leds(canvas: &[Vec<char>]) -> usize {
-        canvas
-            .iter()
-            .map(|block| block.iter().filter(|c| **c == '#').count())
-            .sum()
+        canvas.iter().filter(|c| **c == '#').count()
     }
 }
 
@@ -74,24 +55,12 @@ impl Solution for Solution21 {
     fn solve_version01(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
         let rules = Self::parse(input);
         let iterations = if is_sample { 2 } else { 5 };
-        let mut canvas = vec![vec!['.', '#', '.'], vec!['.', '.', '#'], vec!['#', '#', '#']];
-
-        for _ in 0..iterations {
-            canvas = Self::enhance(canvas, &rules);
-        }
-
-        Self::count_leds(&canvas).to_result()
+        Self::run(&rules, iterations).to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
         let rules = Self::parse(input);
         let iterations = if is_sample { 2 } else { 18 };
-        let mut canvas = vec![vec!['.', '#', '.'], vec!['.', '.', '#'], vec!['#', '#', '#']];
-
-        for _ in 0..iterations {
-            canvas = Self::enhance(canvas, &rules);
-        }
-
-        Self::count_leds(&canvas).to_result()
+        Self::run(&rules, iterations).to_result()
     }
 }
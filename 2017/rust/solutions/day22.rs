@@ -16,7 +16,7 @@ pub struct Solution22;
 impl Solution22 {
     fn parse(input: ProblemInput) -> HashMap<Position, State> {
         let grid = input.grid();
-        let center = Position::new(grid.size.width as i128 / 2, grid.size.height as i128 / 2);
+        let center = Position::new(grid.size().width as i128 / 2, grid.size().height as i128 / 2);
 
         grid.enumerate()
             .filter(|(_, c)| **c == '#')
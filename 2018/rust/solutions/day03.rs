@@ -1,12 +1,13 @@
-use std::collections::HashSet;
-
+use aoc_lib::cartesian::cuboid::{Compression, Cuboid};
+use aoc_lib::cartesian::point::Point;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-use itertools::iproduct;
+
+type Claim = (u32, Cuboid<2, usize>);
 
 pub struct Solution03;
 impl Solution03 {
-    fn parse(input: ProblemInput) -> Vec<(u32, usize, usize, usize, usize)> {
+    fn parse(input: ProblemInput) -> Vec<Claim> {
         input
             .lines()
             .into_iter()
@@ -16,24 +17,15 @@ impl Solution03 {
                 let (x, y) = topright.split_once(',').unwrap();
                 let (w, h) = size.split_once('x').unwrap();
 
+                let min: Point<2, usize> = Point::new([x.trim().parse().unwrap(), y.trim().parse().unwrap()]);
+                let size = Point::new([w.trim().parse().unwrap(), h.trim().parse().unwrap()]);
                 (
                     id.strip_prefix('#').unwrap().trim().parse().unwrap(),
-                    x.trim().parse().unwrap(),
-                    y.trim().parse().unwrap(),
-                    w.trim().parse().unwrap(),
-                    h.trim().parse().unwrap(),
+                    Cuboid::new(min, min + size),
                 )
             })
             .collect()
     }
-
-    fn claim_fabric(fabric: &mut [Vec<HashSet<u32>>], claims: &[(u32, usize, usize, usize, usize)]) {
-        for (id, cx, cy, cw, ch) in claims.iter().copied() {
-            for (x, y) in iproduct!(cx..cx + cw, cy..cy + ch) {
-                fabric[y][x].insert(id);
-            }
-        }
-    }
 }
 
 impl Solution for Solution03 {
@@ -48,32 +40,35 @@ impl Solution for Solution03 {
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let claims = Self::parse(input);
-        let mut fabric = vec![vec![HashSet::new(); 1000]; 1000];
-        Self::claim_fabric(&mut fabric, &claims);
+        let fabric = Compression::new(claims.iter().map(|(_, claim)| claim));
+
+        // Count claims per compressed cell, each of which is a rectangle of fabric
+        let mut counts = vec![0; fabric.len()];
+        for (_, claim) in &claims {
+            for idx in fabric.indices(claim) {
+                counts[fabric.offset(&idx)] += 1;
+            }
+        }
 
         fabric
-            .into_iter()
-            .flatten()
-            .filter(|ids| ids.len() > 1)
-            .count()
+            .cells()
+            .zip(counts)
+            .filter(|(_, count)| *count > 1)
+            .map(|(idx, _)| fabric.cell(&idx).volume())
+            .sum::<usize>()
             .to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let claims = Self::parse(input);
-        let mut fabric = vec![vec![HashSet::new(); 1000]; 1000];
-        Self::claim_fabric(&mut fabric, &claims);
-
-        let overlaps: HashSet<_> = fabric
-            .into_iter()
-            .flatten()
-            .filter(|ids| ids.len() > 1)
-            .flatten()
-            .collect();
 
         claims
-            .into_iter()
-            .find(|(id, ..)| !overlaps.contains(id))
+            .iter()
+            .find(|(id, claim)| {
+                claims
+                    .iter()
+                    .all(|(other_id, other)| id == other_id || !claim.intersects(other))
+            })
             .unwrap()
             .0
             .to_result()
@@ -1,3 +1,5 @@
+use aoc_lib::cartesian::Position;
+use aoc_lib::ocr;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use itertools::Itertools;
@@ -24,7 +26,8 @@ impl Solution10 {
             .collect()
     }
 
-    fn find_message(mut lights: Vec<((i32, i32), (i32, i32))>, is_sample: bool) -> (String, usize) {
+    /// Positions of the lights once they form the message, and the time when that happens
+    fn find_message(mut lights: Vec<((i32, i32), (i32, i32))>, is_sample: bool) -> (Vec<Position>, usize) {
         let num_lines = if is_sample { 2 } else { 8 };
         let line_len = 8;
 
@@ -44,24 +47,11 @@ impl Solution10 {
                 .collect_vec();
 
             if lines.len() >= num_lines {
-                // let (min_x, max_x) = (lines[0][0].0, lines[lines.len() - 1][0].0);
-                // let (min_y, max_y) = (lines[0][0].1, lines[0][line_len - 1].1);
-
-                // println!();
-                // for y in (min_y - 1)..=(max_y + 1) {
-                //     for x in (min_x - 10)..=(max_x + 10) {
-                //         if lights.iter().any(|((px, py), _)| x == *px && y == *py) {
-                //             print!("#");
-                //         } else {
-                //             print!(" ");
-                //         }
-                //     }
-                //     println!()
-                // }
-
-                // Print lights and see outcome
-                let text = (if is_sample { "HI" } else { "CPJRNKCF" }).to_string();
-                return (text, s);
+                let positions = lights
+                    .iter()
+                    .map(|((px, py), _)| Position::new(*px as i128, *py as i128))
+                    .collect_vec();
+                return (positions, s);
             }
         }
 
@@ -72,7 +62,7 @@ impl Solution10 {
 impl Solution for Solution10 {
     fn results(&self) -> [ProblemResult; 4] {
         [
-            ProblemResult::String("HI".to_string()),
+            ProblemResult::NoSample,
             ProblemResult::String("CPJRNKCF".to_string()),
             ProblemResult::USize(3),
             ProblemResult::USize(10345),
@@ -80,10 +70,14 @@ impl Solution for Solution10 {
     }
 
     fn solve_version01(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
-        let lights = Self::parse(input);
+        // The letters of the sample are not in one of the known fonts
+        if is_sample {
+            return ProblemResult::NoSample;
+        }
 
+        let lights = Self::parse(input);
         let (message, _) = Self::find_message(lights, is_sample);
-        message.to_result()
+        ocr::read_positions(&message).unwrap().to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
@@ -94,7 +94,7 @@ impl Solution15 {
                 .collect();
             let in_range_squares = targets
                 .iter()
-                .flat_map(|t| t.borrow().idx.von_neumann_neighbors(caves.size))
+                .flat_map(|t| t.borrow().idx.von_neumann_neighbors(caves.size()))
                 .filter(|sq| *caves.get(sq) && !entity_squares.contains(sq))
                 .unique()
                 .collect_vec();
@@ -103,7 +103,7 @@ impl Solution15 {
             if let Some(chosen_square) = chosen {
                 let step = Self::find_chosen_square(
                     chosen_square,
-                    &curr_idx.von_neumann_neighbors(caves.size),
+                    &curr_idx.von_neumann_neighbors(caves.size()),
                     caves,
                     &entity_squares,
                 );
@@ -144,7 +144,7 @@ impl Solution15 {
             }
 
             seen.insert(idx);
-            for nb in idx.von_neumann_neighbors(caves.size) {
+            for nb in idx.von_neumann_neighbors(caves.size()) {
                 queue.push(Reverse((dist + 1, nb)))
             }
         }
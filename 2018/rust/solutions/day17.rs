@@ -64,7 +64,7 @@ impl Solution17 {
 
         loop {
             ground.set(&idx, Ground::Flowing);
-            let Some(down) = idx.advance_check(Direction::South, ground.size) else {
+            let Some(down) = idx.advance_check(Direction::South, ground.size()) else {
                 return;
             };
 
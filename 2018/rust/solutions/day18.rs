@@ -1,4 +1,4 @@
-use aoc_lib::algo;
+use aoc_lib::cartesian::automaton::{self, Automaton};
 use aoc_lib::cartesian::Grid;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -22,28 +22,19 @@ impl Solution18 {
         })
     }
 
-    fn magic(state: Grid<Acre>) -> Grid<Acre> {
-        let mut new_state = Grid::empty(state.size, Acre::Open);
+    fn magic(acre: &Acre, neighbors: &[&Acre]) -> Acre {
+        let nb_acres = neighbors.iter().counts();
+        let adj_trees = *nb_acres.get(&&Acre::Tree).unwrap_or(&0);
+        let adj_lumber = *nb_acres.get(&&Acre::Lumberyard).unwrap_or(&0);
 
-        for (idx, acre) in state.enumerate() {
-            let nbs = idx.moore_neighbors(state.size);
-            let nb_acres = nbs.into_iter().map(|idx| state.get(&idx)).counts();
-            let adj_trees = *nb_acres.get(&Acre::Tree).unwrap_or(&0);
-            let adj_lumber = *nb_acres.get(&Acre::Lumberyard).unwrap_or(&0);
-
-            let new_acre = match acre {
-                Acre::Open if adj_trees >= 3 => Acre::Tree,
-                Acre::Open => Acre::Open,
-                Acre::Tree if adj_lumber >= 3 => Acre::Lumberyard,
-                Acre::Tree => Acre::Tree,
-                Acre::Lumberyard if adj_lumber >= 1 && adj_trees >= 1 => Acre::Lumberyard,
-                Acre::Lumberyard => Acre::Open,
-            };
-
-            new_state.set(&idx, new_acre);
+        match acre {
+            Acre::Open if adj_trees >= 3 => Acre::Tree,
+            Acre::Open => Acre::Open,
+            Acre::Tree if adj_lumber >= 3 => Acre::Lumberyard,
+            Acre::Tree => Acre::Tree,
+            Acre::Lumberyard if adj_lumber >= 1 && adj_trees >= 1 => Acre::Lumberyard,
+            Acre::Lumberyard => Acre::Open,
         }
-
-        new_state
     }
 }
 
@@ -58,11 +49,7 @@ impl Solution for Solution18 {
     }
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let mut state = Self::parse(input);
-
-        for _ in 0..10 {
-            state = Self::magic(state);
-        }
+        let state = Automaton::new(automaton::moore, Self::magic).run(Self::parse(input), 10);
 
         let counts = state.iter().counts();
         (counts[&Acre::Tree] * counts[&Acre::Lumberyard]).to_result()
@@ -71,7 +58,7 @@ impl Solution for Solution18 {
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let state = Self::parse(input);
 
-        let final_state = algo::find_final_state(state, Self::magic, 1_000_000_000);
+        let final_state = Automaton::new(automaton::moore, Self::magic).final_state(state, 1_000_000_000);
         let counts = final_state.iter().counts();
 
         (counts.get(&Acre::Tree).unwrap_or(&0) * counts.get(&Acre::Lumberyard).unwrap_or(&0)).to_result()
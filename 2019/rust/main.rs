@@ -96,4 +96,10 @@ mod tests2019 {
     test_day!(day23);
     test_day!(day24);
     test_day!(day25);
+
+    mod streamed {
+        use aoc_lib::test_streamed;
+
+        test_streamed!(day01; version01, version02);
+    }
 }
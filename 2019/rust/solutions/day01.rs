@@ -2,10 +2,20 @@ use std::collections::VecDeque;
 
 use aoc_lib::iterator::ParsedExt;
 use aoc_lib::prelude::solution::Solution;
-use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
+use aoc_lib::prelude::types::{ProblemInput, ProblemResult, StreamedInput, ToResult};
 
 pub struct Solution01;
-impl Solution01 {}
+impl Solution01 {
+    fn total_fuel(mass: i64) -> i64 {
+        let mut fuel = 0;
+        let mut m = mass;
+        while m / 3 - 2 > 0 {
+            m = m / 3 - 2;
+            fuel += m;
+        }
+        fuel
+    }
+}
 
 impl Solution for Solution01 {
     fn results(&self) -> [ProblemResult; 4] {
@@ -41,4 +51,13 @@ impl Solution for Solution01 {
 
         total_fuel.to_result()
     }
+
+    // Streamed inputs can be large enough for the sums to overflow i32
+    fn solve_version01_streamed(&self, input: StreamedInput, _is_sample: bool) -> ProblemResult {
+        input.parsed::<i64>().map(|m| m / 3 - 2).sum::<i64>().to_result()
+    }
+
+    fn solve_version02_streamed(&self, input: StreamedInput, _is_sample: bool) -> ProblemResult {
+        input.parsed::<i64>().map(Self::total_fuel).sum::<i64>().to_result()
+    }
 }
@@ -1,3 +1,5 @@
+use aoc_lib::cartesian::{Grid, Size};
+use aoc_lib::ocr;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use itertools::Itertools;
@@ -21,7 +23,7 @@ impl Solution for Solution08 {
         [
             ProblemResult::USize(1),
             ProblemResult::USize(1340),
-            ProblemResult::String("/".to_string()),
+            ProblemResult::NoSample,
             ProblemResult::String("LEJKC".to_string()),
         ]
     }
@@ -40,10 +42,14 @@ impl Solution for Solution08 {
     }
 
     fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
-        let (w, h) = if is_sample { (2, 2) } else { (25, 6) };
+        // The sample image is a single diagonal instead of letters
+        if is_sample {
+            return ProblemResult::NoSample;
+        }
+
+        let (w, h) = (25, 6);
         let layers = Self::parse(input, w, h);
 
-        #[allow(unused_variables)]
         let final_image = layers.into_iter().fold(vec![2; w * h], |image, layer| {
             image
                 .into_iter()
@@ -56,15 +62,7 @@ impl Solution for Solution08 {
                 .collect()
         });
 
-        // --- Print to see result ---
-        // println!();
-        // for j in 0..h {
-        //     for i in 0..w {
-        //         print!("{}", if final_image[j * w + i] == 1 { "#" } else { " " })
-        //     }
-        //     println!()
-        // }
-
-        (if is_sample { "/" } else { "LEJKC" }).to_result()
+        let image = Grid::from_cells(Size::new(w, h), final_image.into_iter().map(|px| px == 1).collect());
+        ocr::read_grid(&image).unwrap().to_result()
     }
 }
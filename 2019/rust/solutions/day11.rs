@@ -1,19 +1,17 @@
-use std::collections::HashMap;
-
-use aoc_lib::cartesian::{Direction, Position};
+use aoc_lib::cartesian::{Direction, Position, SparseGrid};
+use aoc_lib::ocr;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use aoc_lib::specific::intcode::Program;
-use itertools::Itertools;
 
 pub struct Solution11;
 impl Solution11 {
-    fn run_robot(mut brain: Program, panels: &mut HashMap<Position, i128>) {
+    fn run_robot(mut brain: Program, panels: &mut SparseGrid<i128>) {
         let mut pos = Position::zero();
         let mut dir = Direction::North;
 
         loop {
-            let color = *panels.get(&pos).unwrap_or(&0);
+            let color = *panels.get(&pos);
             brain.input.push_back(color);
             let Some(paint) = brain.execute_until_output() else {
                 break;
@@ -22,7 +20,7 @@ impl Solution11 {
                 break;
             };
 
-            panels.insert(pos, paint);
+            panels.set(&pos, paint);
             dir = match turn {
                 0 => dir.left(),
                 1 => dir.right(),
@@ -32,27 +30,6 @@ impl Solution11 {
             pos = pos.advance_by(dir, 1);
         }
     }
-
-    #[allow(dead_code)]
-    fn print_panels(panels: &HashMap<Position, i128>) {
-        let Some((min_x, max_x)) = panels.keys().map(|pos| pos.x).minmax().into_option() else { panic!() };
-        let Some((min_y, max_y)) = panels.keys().map(|pos| pos.y).minmax().into_option() else { panic!() };
-
-        println!();
-        for y in min_y - 1..=max_y + 1 {
-            for x in min_x - 1..=max_x + 1 {
-                print!(
-                    "{}",
-                    if *panels.get(&Position { x, y }).unwrap_or(&0) == 1 {
-                        '#'
-                    } else {
-                        ' '
-                    }
-                )
-            }
-            println!();
-        }
-    }
 }
 
 impl Solution for Solution11 {
@@ -71,7 +48,7 @@ impl Solution for Solution11 {
         }
 
         let brain = Program::init(&input.string());
-        let mut panels = HashMap::new();
+        let mut panels = SparseGrid::new(0);
         Self::run_robot(brain, &mut panels);
 
         panels.len().to_result()
@@ -83,11 +60,11 @@ impl Solution for Solution11 {
         }
 
         let brain = Program::init(&input.string());
-        let mut panels = HashMap::from([(Position::zero(), 1)]);
+        let mut panels = SparseGrid::new(0);
+        panels.set(&Position::zero(), 1);
         Self::run_robot(brain, &mut panels);
 
-        // --- Print to see message
-        // Self::print_panels(&panels);
-        "PGUEPLPR".to_result()
+        let white = panels.iter().filter(|(_, color)| **color == 1).map(|(pos, _)| pos);
+        ocr::read_positions(white).unwrap().to_result()
     }
 }
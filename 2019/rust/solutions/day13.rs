@@ -27,10 +27,7 @@ impl Solution for Solution13 {
         let mut screen = HashMap::new();
         let mut game = Program::init(&input.string());
 
-        loop {
-            let Some(x) = game.execute_until_output() else {
-                break;
-            };
+        while let Some(x) = game.execute_until_output() {
             let Some(y) = game.execute_until_output() else {
                 break;
             };
@@ -177,7 +177,7 @@ impl Solution for Solution17 {
             .filter(|(idx, scaff)| {
                 **scaff
                     && idx
-                        .von_neumann_neighbors(grid.size)
+                        .von_neumann_neighbors(grid.size())
                         .into_iter()
                         .filter(|nb| *grid.get(nb))
                         .count()
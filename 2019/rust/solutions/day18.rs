@@ -1,8 +1,7 @@
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
-use std::fmt::Display;
 
-use aoc_lib::cartesian::{Direction, Grid};
+use aoc_lib::cartesian::{Direction, Grid, GridCell};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use itertools::Itertools;
@@ -17,30 +16,30 @@ enum Tile {
     Door(char),
 }
 
-impl Display for Tile {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Entrance => write!(f, "@"),
-            Self::SubEntrance(i) => write!(f, "{}", i),
-            Self::Passage => write!(f, "."),
-            Self::Wall => write!(f, "#"),
-            Self::Key(k) => write!(f, "{}", k),
-            Self::Door(k) => write!(f, "{}", k.to_ascii_uppercase()),
-        }
-    }
-}
-
-impl Tile {
-    fn from_char(c: &char) -> Self {
+impl GridCell for Tile {
+    fn from_char(c: char) -> Self {
         match c {
             '@' => Tile::Entrance,
             '#' => Tile::Wall,
             '.' => Tile::Passage,
-            _ if c.is_ascii_lowercase() => Tile::Key(*c),
+            _ if c.is_ascii_lowercase() => Tile::Key(c),
             _ => Tile::Door(c.to_ascii_lowercase()),
         }
     }
 
+    fn to_char(&self) -> char {
+        match self {
+            // Sub-entrances only come from splitting the entrance, they are shown as entrances again
+            Self::Entrance | Self::SubEntrance(_) => '@',
+            Self::Passage => '.',
+            Self::Wall => '#',
+            Self::Key(k) => *k,
+            Self::Door(k) => k.to_ascii_uppercase(),
+        }
+    }
+}
+
+impl Tile {
     fn is_keypoint(&self) -> bool {
         match self {
             Tile::Entrance => true,
@@ -104,7 +103,7 @@ impl Solution18 {
                 let mut found = HashMap::new();
 
                 while let Some((pos, steps, keys, doors)) = queue.pop_front() {
-                    for next_pos in pos.von_neumann_neighbors(map.size) {
+                    for next_pos in pos.von_neumann_neighbors(map.size()) {
                         let tile = *map.get(&next_pos);
                         if !matches!(tile, Tile::Wall) && !seen.contains(&next_pos) {
                             seen.insert(next_pos);
@@ -188,7 +187,7 @@ impl Solution for Solution18 {
     }
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let map = input.grid().map_elements(Tile::from_char);
+        let map = input.grid_of::<Tile>();
         let graph = Self::create_graph(&map);
 
         let all_keys = map
@@ -200,7 +199,7 @@ impl Solution for Solution18 {
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let mut map = input.grid().map_elements(Tile::from_char);
+        let mut map = input.grid_of::<Tile>();
 
         // Transform entrance
         let main = map
@@ -209,7 +208,7 @@ impl Solution for Solution18 {
             .unwrap()
             .0;
         map.set(&main, Tile::Wall);
-        for idx in main.von_neumann_neighbors(map.size) {
+        for idx in main.von_neumann_neighbors(map.size()) {
             map.set(&idx, Tile::Wall);
         }
         let north = main.advance(Direction::North);
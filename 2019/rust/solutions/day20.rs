@@ -54,7 +54,7 @@ impl Solution20 {
             _ => Tile::None,
         });
         let mut seen = HashSet::new();
-        let size = char_grid.size;
+        let size = char_grid.size();
 
         // Parse portals
         for idx in size.indices() {
@@ -136,7 +136,7 @@ impl Solution20 {
             // Progress
             match tile {
                 Tile::Path => {
-                    for nb in idx.von_neumann_neighbors(grid.size) {
+                    for nb in idx.von_neumann_neighbors(grid.size()) {
                         match grid.get(&nb) {
                             Tile::Path | Tile::Portal(_, _, _) => queue.push((Reverse(steps + 1), Reverse(level), nb)),
                             _ => (),
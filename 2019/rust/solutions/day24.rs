@@ -1,6 +1,7 @@
 use core::panic;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
+use aoc_lib::cartesian::automaton::{self, Automaton, SparseAutomaton};
 use aoc_lib::cartesian::{Grid, Index, Size};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -9,68 +10,20 @@ use itertools::Itertools;
 pub struct Solution24;
 impl Solution24 {
     fn parse(input: ProblemInput) -> Grid<bool> {
-        input.grid().map_elements(|c| *c == '#')
+        input.grid_of::<bool>()
     }
 
-    fn next(state: Grid<bool>) -> Grid<bool> {
-        let mut new_grid = Grid::empty(state.size, false);
-        for idx in state.size.indices() {
-            let adjacent = idx
-                .von_neumann_neighbors(state.size)
-                .into_iter()
-                .filter(|nb| *state.get(nb))
-                .count();
-
-            if adjacent == 1 || (!*state.get(&idx) && adjacent == 2) {
-                new_grid.set(&idx, true);
-            }
-        }
-
-        new_grid
+    fn infest(bug: bool, adjacent: usize) -> bool {
+        adjacent == 1 || (!bug && adjacent == 2)
     }
 
     fn biodiversity(state: &Grid<bool>) -> u32 {
         state.iter().rev().fold(0u32, |rating, bug| (rating << 1) + *bug as u32)
     }
 
-    fn next_recursive(states: HashMap<i32, Grid<bool>>) -> HashMap<i32, Grid<bool>> {
-        let mut new_states = HashMap::new();
-
-        // Compute each layer
-        let size = Size::square(5);
-        let Some((min, max)) = states.keys().minmax().into_option() else { unreachable!() };
-        for level in min - 1..=max + 1 {
-            let mut new_grid = Grid::empty(size, false);
-            for idx in size.indices() {
-                if idx.i == 2 && idx.j == 2 {
-                    continue;
-                }
-                let adjacent = Self::neighbors_recursive(level, idx, size)
-                    .into_iter()
-                    .filter(|(nl, nb)| *states.get(nl).map(|state| state.get(nb)).unwrap_or(&false))
-                    .count();
-
-                let bug = *states.get(&level).map(|state| state.get(&idx)).unwrap_or(&false);
-                if adjacent == 1 || (!bug && adjacent == 2) {
-                    new_grid.set(&idx, true);
-                }
-            }
-
-            new_states.insert(level, new_grid);
-        }
-
-        // Remove outer layers if empty to save computations
-        for outer in [min - 1, max + 1] {
-            if new_states[&outer].iter().all(|bug| !*bug) {
-                new_states.remove(&outer);
-            }
-        }
-
-        new_states
-    }
-
-    fn neighbors_recursive(level: i32, idx: Index, size: Size) -> Vec<(i32, Index)> {
+    fn neighbors_recursive(&(level, idx): &(i32, Index)) -> Vec<(i32, Index)> {
         // Start with basic Von Neumann neighborhood
+        let size = Size::square(5);
         let neumann = idx
             .von_neumann_neighbors(size)
             .into_iter()
@@ -119,6 +72,9 @@ impl Solution for Solution24 {
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let mut state = Self::parse(input);
+        let bugs = Automaton::new(automaton::von_neumann, |bug: &bool, neighbors: &[&bool]| {
+            Self::infest(*bug, neighbors.iter().filter(|nb| ***nb).count())
+        });
 
         // Use biodiversity instead of grid in hashset, as the rating is essentially a integer representation of it
         let mut seen = HashSet::new();
@@ -129,19 +85,19 @@ impl Solution for Solution24 {
             }
             seen.insert(rating);
 
-            state = Self::next(state);
+            state = bugs.step(&state);
         }
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let initial_states = HashMap::from([(0, Self::parse(input))]);
+        let initial_bugs: HashSet<_> = Self::parse(input)
+            .enumerate()
+            .filter(|(_, bug)| **bug)
+            .map(|(idx, _)| (0, idx))
+            .collect();
         let minutes = if _is_sample { 10 } else { 200 };
 
-        let final_states = (0..minutes).fold(initial_states, |states, _| Self::next_recursive(states));
-        final_states
-            .values()
-            .map(|state| state.iter().filter(|bug| **bug).count())
-            .sum::<usize>()
-            .to_result()
+        let bugs = SparseAutomaton::new(Self::neighbors_recursive, Self::infest);
+        bugs.run(initial_bugs, minutes).len().to_result()
     }
 }
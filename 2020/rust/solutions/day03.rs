@@ -5,9 +5,9 @@ use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 pub struct Solution03;
 impl Solution03 {
     fn trees(area: &Grid<char>, dx: usize, dy: usize) -> usize {
-        (0..area.size.height)
+        (0..area.size().height)
             .step_by(dy)
-            .map(|j| Index::new((j * dx / dy) % area.size.width, j))
+            .map(|j| Index::new((j * dx / dy) % area.size().width, j))
             .filter(|idx| *area.get(idx) == '#')
             .count()
     }
@@ -52,10 +52,10 @@ impl Solution20 {
                 for (i, flip_rot_grid) in grid.symmetry_group().into_iter().enumerate() {
                     let flip_rot_grid_transposed = flip_rot_grid.transpose();
 
-                    let edge_top = Self::edge_to_uint(&flip_rot_grid.rows[0]);
-                    let edge_bot = Self::edge_to_uint(&flip_rot_grid.rows[flip_rot_grid.rows.len() - 1]);
-                    let edge_left = Self::edge_to_uint(&flip_rot_grid_transposed.rows[0]);
-                    let edge_right = Self::edge_to_uint(&flip_rot_grid_transposed.rows[flip_rot_grid.rows.len() - 1]);
+                    let edge_top = Self::edge_to_uint(flip_rot_grid.row(0));
+                    let edge_bot = Self::edge_to_uint(flip_rot_grid.rows().last().unwrap());
+                    let edge_left = Self::edge_to_uint(flip_rot_grid_transposed.row(0));
+                    let edge_right = Self::edge_to_uint(flip_rot_grid_transposed.rows().last().unwrap());
                     edges[i] = Edges::new(edge_top, edge_left, edge_bot, edge_right);
                 }
 
@@ -85,7 +85,7 @@ impl Solution20 {
                 // Check if tile fits
                 if !image.is_empty() {
                     // Check left border
-                    if image.len() % width != 0 {
+                    if !image.len().is_multiple_of(width) {
                         let left_tile = image.last().unwrap();
                         if edges.left != left_tile.1.right {
                             continue;
@@ -118,67 +118,31 @@ impl Solution20 {
 
     fn build_image(image_tiles: &[PlacedTile], tile_grids: &[(Tile, Grid<bool>)]) -> Grid<bool> {
         let tiled_size = image_tiles.len().sqrt();
-        let cropped_tile_size = tile_grids[0].1.size.width - 2;
-        let mut image = Grid::empty(Size::square(cropped_tile_size * tiled_size), false);
-
-        for (idx, (tile_id, flip_rot)) in image_tiles.iter().enumerate() {
-            // Find actual grid and the flip and rotation
-            let ((_, edges), tile_grid) = tile_grids.iter().find(|((tg_id, _), _)| tile_id == tg_id).unwrap();
-            let flip_rot_id = edges.iter().position(|e| e == flip_rot).unwrap();
-
-            // Apply transformations to have same grid as was placed when assembling it
-            let grid = &tile_grid.symmetry_group()[flip_rot_id];
-
-            // Compute tile coordinates
-            let ti = idx % tiled_size;
-            let tj = idx / tiled_size;
-
-            // Set grid in image
-            for dj in 1..=cropped_tile_size {
-                for di in 1..=cropped_tile_size {
-                    let image_idx = Index::new(ti * cropped_tile_size + di - 1, tj * cropped_tile_size + dj - 1);
-                    let tile_idx = Index::new(di, dj);
-                    image.set(&image_idx, *grid.get(&tile_idx));
-                }
-            }
-        }
+        let cropped_tile_size = Size::square(tile_grids[0].1.size().width - 2);
 
-        image
-    }
+        let tiles = image_tiles
+            .iter()
+            .map(|(tile_id, flip_rot)| {
+                // Find actual grid and the flip and rotation
+                let ((_, edges), tile_grid) = tile_grids.iter().find(|((tg_id, _), _)| tile_id == tg_id).unwrap();
+                let flip_rot_id = edges.iter().position(|e| e == flip_rot).unwrap();
 
-    fn create_seamonster() -> (Vec<Index>, Size) {
-        let seamonster = "                  # \n#    ##    ##    ###\n #  #  #  #  #  #   ";
-        let seamonster_indices = seamonster
-            .split('\n')
-            .enumerate()
-            .flat_map(|(j, row)| {
-                row.bytes()
-                    .enumerate()
-                    .filter_map(move |(i, c)| (c == b'#').then_some(Index { i, j }))
+                // Apply transformations to have same grid as was placed when assembling it, without borders
+                tile_grid.symmetry_group()[flip_rot_id].crop(Index::new(1, 1), cropped_tile_size)
             })
-            .collect_vec();
-
-        let width = seamonster_indices.iter().map(|idx| idx.i).max().unwrap() + 1;
-        let height = seamonster_indices.iter().map(|idx| idx.j).max().unwrap() + 1;
+            .collect();
 
-        (seamonster_indices, Size::new(width, height))
+        Grid::from_tiles(Grid::from_cells(Size::square(tiled_size), tiles))
     }
 
-    fn find_seamonster(image: &Grid<bool>, seamonster: &[Index], seamonster_size: &Size) -> HashSet<Index> {
-        let mut seamonster_tiles = HashSet::new();
-        for j in 0..image.size.height - seamonster_size.height {
-            for i in 0..image.size.width - seamonster_size.width {
-                let idcs = seamonster
-                    .iter()
-                    .map(|offset| Index::new(i + offset.i, j + offset.j))
-                    .collect_vec();
-                if idcs.iter().all(|idx| *image.get(idx)) {
-                    seamonster_tiles.extend(idcs);
-                }
-            }
-        }
-
-        seamonster_tiles
+    fn create_seamonster() -> Grid<Option<bool>> {
+        let seamonster = ["                  # ", "#    ##    ##    ###", " #  #  #  #  #  #   "];
+        Grid::new(
+            seamonster
+                .iter()
+                .map(|row| row.chars().map(|c| (c == '#').then_some(true)).collect_vec())
+                .collect_vec(),
+        )
     }
 }
 
@@ -208,19 +172,19 @@ impl Solution for Solution20 {
         let _ = Self::assemble_tiles(&mut image_tiles, &mut tiles, width).unwrap();
 
         let image = Self::build_image(&image_tiles, &tile_grids);
-        let (seamonster_idcs, seamonster_size) = Self::create_seamonster();
-
-        for flip_rot_image in image.symmetry_group() {
-            let seamonster_tiles = Self::find_seamonster(&flip_rot_image, &seamonster_idcs, &seamonster_size);
-            if !seamonster_tiles.is_empty() {
-                let roughness = flip_rot_image
-                    .enumerate()
-                    .filter(|(idx, water)| **water && !seamonster_tiles.contains(idx))
-                    .count();
-                return roughness.to_result();
-            }
-        }
+        // Only one orientation of the image contains sea monsters
+        let seamonsters = image.find_pattern_symmetries(&Self::create_seamonster());
+        let orientation = seamonsters[0].symmetry;
+        let seamonster_tiles: HashSet<Index> = seamonsters
+            .into_iter()
+            .filter(|seamonster| seamonster.symmetry == orientation)
+            .flat_map(|seamonster| seamonster.cells)
+            .collect();
 
-        unreachable!()
+        let roughness = image
+            .enumerate()
+            .filter(|(idx, water)| **water && !seamonster_tiles.contains(idx))
+            .count();
+        roughness.to_result()
     }
 }
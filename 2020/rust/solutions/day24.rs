@@ -1,56 +1,29 @@
 use std::collections::HashSet;
 
-use aoc_lib::cartesian::{HexDirection, HexIndex};
+use aoc_lib::cartesian::automaton::{self, SparseAutomaton};
+use aoc_lib::cartesian::hex::{Axial, HexGrid, HexOrientation};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-use itertools::Itertools;
 
 pub struct Solution24;
 impl Solution24 {
-    fn parse(input: ProblemInput) -> Vec<Vec<HexDirection>> {
+    fn parse(input: ProblemInput) -> Vec<Vec<Axial>> {
         input
             .lines()
             .into_iter()
-            .map(|line| {
-                let mut dirs = Vec::new();
-                let mut i = 0;
-                while i < line.len() {
-                    // We rotate all directions, beucase struct support flat up grid, problem has pointy up
-                    dirs.push(match &line[i..=i] {
-                        d @ "n" | d @ "s" => {
-                            i += 1;
-                            match (d, &line[i..=i]) {
-                                ("n", "w") => HexDirection::SouthWest,
-                                ("n", "e") => HexDirection::NorthWest,
-                                ("s", "w") => HexDirection::SouthEast,
-                                ("s", "e") => HexDirection::NorthEast,
-                                _ => unreachable!(),
-                            }
-                        }
-                        "w" => HexDirection::South,
-                        "e" => HexDirection::North,
-                        _ => unreachable!(),
-                    });
-                    i += 1;
-                }
-                dirs
-            })
+            .map(|line| HexOrientation::PointyTop.parse_path(&line))
             .collect()
     }
 
-    fn flip_tiles(paths: &[Vec<HexDirection>]) -> HashSet<HexIndex> {
-        let mut blacks = HashSet::new();
+    fn flip_tiles(paths: &[Vec<Axial>]) -> HashSet<Axial> {
+        let mut floor = HexGrid::new(false);
         for path in paths {
-            let target = path.iter().fold(HexIndex { x: 0, y: 0 }, |idx, dir| idx.step(dir));
-
-            if !blacks.contains(&target) {
-                blacks.insert(target);
-            } else {
-                blacks.remove(&target);
-            }
+            let target = path.iter().fold(Axial::zero(), |hex, step| hex + *step);
+            let black = floor.get_mut(&target);
+            *black = !*black;
         }
 
-        blacks
+        floor.hexes_where(|black| *black)
     }
 }
 
@@ -72,21 +45,11 @@ impl Solution for Solution24 {
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let paths = Self::parse(input);
-        let mut blacks = Self::flip_tiles(&paths);
-
-        for _ in 0..100 {
-            let mut new_blacks = HashSet::new();
-
-            for tile in blacks.iter().flat_map(|b| b.neighbors()).unique() {
-                let black_nb = tile.neighbors().iter().filter(|nb| blacks.contains(nb)).count();
-                if black_nb == 2 || (blacks.contains(&tile) && black_nb == 1) {
-                    new_blacks.insert(tile);
-                }
-            }
-
-            blacks = new_blacks
-        }
+        let blacks = Self::flip_tiles(&paths);
 
-        blacks.len().to_result()
+        let exhibit = SparseAutomaton::new(automaton::hex, |black, black_nb| {
+            black_nb == 2 || (black && black_nb == 1)
+        });
+        exhibit.run(blacks, 100).len().to_result()
     }
 }
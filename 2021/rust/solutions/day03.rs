@@ -29,9 +29,8 @@ impl Solution for Solution03 {
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let digits = input.grid().transpose();
         let majorities = digits
-            .rows
-            .into_iter()
-            .map(|ds| ds.iter().filter(|d| **d == '1').count() >= digits.size.width / 2)
+            .rows()
+            .map(|ds| ds.iter().filter(|d| **d == '1').count() >= digits.size().width / 2)
             .collect_vec();
         let gamma_rate = majorities.iter().fold(0, |acc, b| (acc << 1) + (*b as u32));
         let epsilon_rate = majorities.iter().fold(0, |acc, b| (acc << 1) + (!*b as u32));
@@ -40,7 +39,7 @@ impl Solution for Solution03 {
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let diagnostics = input.grid().map_elements(|c| *c == '1').rows;
+        let diagnostics = input.grid().map_elements(|c| *c == '1').into_rows();
 
         let oxygen_generator_rating = Self::rating(diagnostics.clone(), true);
         let co2_scrubber_rating = Self::rating(diagnostics.clone(), false);
@@ -1,3 +1,4 @@
+use aoc_lib::cartesian::lattice;
 use aoc_lib::cartesian::{Grid, Index, Size};
 use aoc_lib::iterator::ParsedExt;
 use aoc_lib::prelude::solution::Solution;
@@ -26,31 +27,17 @@ impl Solution05 {
         let mut area = Grid::empty(Size::new(width, height), 0);
 
         for (start, end) in vents {
-            if start.i == end.i {
-                // Vertical vents
-                for j in start.j..=end.j {
-                    *area.get_mut(&Index::new(start.i, j)) += 1;
-                }
-            } else if start.j == end.j {
-                // Horizontal vents
-                for i in start.i..=end.i {
-                    *area.get_mut(&Index::new(i, start.j)) += 1;
-                }
-            } else if start.i.abs_diff(end.i) == start.j.abs_diff(end.j) {
-                // Diagonal vents
-                if allow_diagonals {
-                    let j_range = start.j..=end.j;
-                    let mut i_range = (start.i.min(end.i)..=start.i.max(end.i)).collect_vec();
-                    if end.i < start.i {
-                        i_range.reverse()
-                    }
+            let (dx, dy) = lattice::reduced_direction(&(*start).into(), &(*end).into());
+            assert!(
+                dx == 0 || dy == 0 || dx.abs() == dy.abs(),
+                "Vents are only straight or diagonal"
+            );
+            if dx != 0 && dy != 0 && !allow_diagonals {
+                continue;
+            }
 
-                    for (i, j) in i_range.into_iter().zip_eq(j_range) {
-                        *area.get_mut(&Index { i, j }) += 1;
-                    }
-                }
-            } else {
-                panic!()
+            for pos in lattice::lattice_line(&(*start).into(), &(*end).into()) {
+                *area.get_mut(&pos.into()) += 1;
             }
         }
 
@@ -1,5 +1,3 @@
-use std::collections::{HashSet, VecDeque};
-
 use aoc_lib::cartesian::{Grid, Index};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -9,7 +7,7 @@ pub struct Solution09;
 impl Solution09 {
     fn find_low_points(height_map: &Grid<u8>) -> impl Iterator<Item = (Index, &u8)> {
         height_map.enumerate().filter(|(idx, height)| {
-            idx.von_neumann_neighbors(height_map.size)
+            idx.von_neumann_neighbors(height_map.size())
                 .into_iter()
                 .map(|nb| height_map.get(&nb))
                 .all(|nbh| nbh > height)
@@ -28,7 +26,7 @@ impl Solution for Solution09 {
     }
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let height_map = input.grid().map_elements(|c| *c as u8 - b'0');
+        let height_map = input.grid_of::<u8>();
 
         let low_points = Self::find_low_points(&height_map);
         let risk_sum = low_points.map(|(_, height)| *height as u32 + 1).sum::<u32>();
@@ -36,29 +34,17 @@ impl Solution for Solution09 {
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let height_map = input.grid().map_elements(|c| *c as u8 - b'0');
-        let low_points = Self::find_low_points(&height_map);
-
-        // Create basins with BFS over each low point
-        let mut basins = Vec::new();
-        for (depression, _) in low_points {
-            let mut basin = HashSet::from([depression]);
-            let mut frontiers = VecDeque::from([depression]);
-            while let Some(frontier) = frontiers.pop_front() {
-                for nb in frontier.von_neumann_neighbors(height_map.size) {
-                    if !basin.contains(&nb) && *height_map.get(&nb) != 9 {
-                        // nb_height > height is true per definition
-                        basin.insert(nb);
-                        frontiers.push_back(nb);
-                    }
-                }
-            }
-
-            basins.push(basin);
-        }
+        let height_map = input.grid_of::<u8>();
 
         // Per definition we know that all positions will be in one basin, and also that the basins never overlap (due to the 9s).
-        let largest_three = basins.into_iter().map(|b| b.len()).sorted().rev().take(3);
+        let basins = height_map.regions_by(|height| *height != 9);
+        let largest_three = basins
+            .into_iter()
+            .filter(|(in_basin, _)| *in_basin)
+            .map(|(_, basin)| basin.area())
+            .sorted()
+            .rev()
+            .take(3);
         largest_three.product::<usize>().to_result()
     }
 }
@@ -17,7 +17,7 @@ impl Solution11 {
         while let Some(octopus) = queue.pop_front() {
             if !flashed.contains(&octopus) {
                 flashed.insert(octopus);
-                for nb in octopus.moore_neighbors(octopi.size) {
+                for nb in octopus.moore_neighbors(octopi.size()) {
                     let energy = octopi.get_mut(&nb);
                     *energy += 1;
                     if *energy > 9 {
@@ -43,14 +43,14 @@ impl Solution for Solution11 {
     }
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let mut octopi = input.grid().map_elements(|c| *c as u8 - b'0');
+        let mut octopi = input.grid_of::<u8>();
         let total_flashes = (0..100).fold(0, |flashes, _| flashes + Self::step(&mut octopi));
         total_flashes.to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let mut octopi = input.grid().map_elements(|c| *c as u8 - b'0');
-        let all_flash = (1..).find(|_| Self::step(&mut octopi) == octopi.size.area()).unwrap();
+        let mut octopi = input.grid_of::<u8>();
+        let all_flash = (1..).find(|_| Self::step(&mut octopi) == octopi.size().area()).unwrap();
         all_flash.to_result()
     }
 }
@@ -1,7 +1,8 @@
 use std::collections::HashSet;
 
-use aoc_lib::cartesian::{Grid, Position, Size};
+use aoc_lib::cartesian::Position;
 use aoc_lib::iterator::ParsedExt;
+use aoc_lib::ocr;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use itertools::Itertools;
@@ -69,12 +70,6 @@ impl Solution for Solution13 {
         let (paper, folds) = Self::parse(input);
         let final_dots = folds.into_iter().fold(paper, |dots, fold| Self::fold(fold, dots));
 
-        let max_x = final_dots.iter().map(|d| d.x).max().unwrap();
-        let max_y = final_dots.iter().map(|d| d.y).max().unwrap();
-
-        let mut display = Grid::empty(Size::new(max_x as usize + 1, max_y as usize + 1), false);
-        final_dots.into_iter().for_each(|d| display.set(&d.into(), true));
-        // display.print(|_, d| if *d { "#" } else { " " });
-        "EFJKZLBL".to_result()
+        ocr::read_positions(&final_dots).unwrap().to_result()
     }
 }
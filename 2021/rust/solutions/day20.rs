@@ -32,9 +32,9 @@ impl Solution20 {
     }
 
     fn enhance_single(image: &Grid<bool>, enhancement_key: &[bool], iteration: usize) -> Grid<bool> {
-        let mut new_image = Grid::empty(image.size, false);
-        for idx in image.size.indices() {
-            let idcs = idx.moore_neighbors(image.size).into_iter().chain([idx]).sorted();
+        let mut new_image = Grid::empty(image.size(), false);
+        for idx in image.size().indices() {
+            let idcs = idx.moore_neighbors(image.size()).into_iter().chain([idx]).sorted();
             let window = idcs.map(|i| image.get(&i)).collect_vec();
 
             // When computing the edges, we need to define how to expand the window.
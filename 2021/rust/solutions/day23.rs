@@ -260,8 +260,8 @@ impl Display for Config {
 pub struct Solution23;
 impl Solution23 {
     fn extend_grid(folded: &mut Grid<char>) {
-        folded.rows.insert(3, "  #D#C#B#A#".chars().collect());
-        folded.rows.insert(4, "  #D#B#A#C#".chars().collect());
+        folded.insert_row(3, "  #D#C#B#A#  ".chars().collect());
+        folded.insert_row(4, "  #D#B#A#C#  ".chars().collect());
     }
 
     fn create_config_graph(cave: Cave) -> DynamicGraph<Config> {
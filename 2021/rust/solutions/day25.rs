@@ -8,10 +8,10 @@ impl Solution25 {
         let mut current = map;
 
         for (herd, dir) in [('>', Direction::East), ('v', Direction::South)] {
-            let mut new_map = Grid::empty(current.size, '.');
+            let mut new_map = Grid::empty(current.size(), '.');
             for (idx, &sc) in current.enumerate() {
                 if sc == herd {
-                    let nb = idx.advance_wrap(dir, current.size);
+                    let nb = idx.advance_wrap(dir, current.size());
                     match current.get(&nb) {
                         '.' => new_map.set(&nb, herd),
                         _ => new_map.set(&idx, herd),
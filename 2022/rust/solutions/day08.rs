@@ -16,13 +16,13 @@ impl Solution for Solution08 {
     }
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let forest = input.grid().map_elements(|c| *c as u8 - b'0');
+        let forest = input.grid_of::<u8>();
 
         let mut visible = 0u32;
         for (idx, height) in forest.enumerate() {
             let is_visible = Direction::compass().into_iter().any(|dir| {
                 let mut tree = idx;
-                while let Some(nb) = tree.advance_check(dir, forest.size) {
+                while let Some(nb) = tree.advance_check(dir, forest.size()) {
                     if forest.get(&nb) >= height {
                         return false;
                     }
@@ -40,13 +40,13 @@ impl Solution for Solution08 {
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let forest = input.grid().map_elements(|c| *c as u8 - b'0');
+        let forest = input.grid_of::<u8>();
 
         let scenic_scores = forest.enumerate().map(|(idx, height)| {
             let views = Direction::compass().into_iter().map(|dir| {
                 let mut view_range = 0u32;
                 let mut tree = idx;
-                while let Some(nb) = tree.advance_check(dir, forest.size) {
+                while let Some(nb) = tree.advance_check(dir, forest.size()) {
                     view_range += 1;
                     if forest.get(&nb) >= height {
                         break;
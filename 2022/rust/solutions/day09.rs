@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use aoc_lib::cartesian::{Direction, Position};
+use aoc_lib::cartesian::{Direction, Direction8, Position};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 
@@ -25,39 +25,10 @@ impl Solution09 {
             for _ in 0..steps {
                 rope[0] = rope[0].advance_by(dir, 1);
                 for i in 1..rope_size {
-                    // After one knot moved, two knots can only be apart by a moore neighborhood of 2. Easily list all cases.
-                    let (delta_x, delta_y) = (rope[i - 1].x - rope[i].x, rope[i - 1].y - rope[i].y);
-                    match (delta_x, delta_y) {
-                        (-2, -2) => rope[i] = rope[i].advance_by(Direction::West, 1).advance_by(Direction::North, 1),
-                        (-2, -1) => rope[i] = rope[i].advance_by(Direction::West, 1).advance_by(Direction::North, 1),
-                        (-2, 0) => rope[i] = rope[i].advance_by(Direction::West, 1),
-                        (-2, 1) => rope[i] = rope[i].advance_by(Direction::West, 1).advance_by(Direction::South, 1),
-                        (-2, 2) => rope[i] = rope[i].advance_by(Direction::West, 1).advance_by(Direction::South, 1),
-
-                        (-1, -2) => rope[i] = rope[i].advance_by(Direction::North, 1).advance_by(Direction::West, 1),
-                        (-1, -1) => (),
-                        (-1, 0) => (),
-                        (-1, 1) => (),
-                        (-1, 2) => rope[i] = rope[i].advance_by(Direction::South, 1).advance_by(Direction::West, 1),
-
-                        (0, -2) => rope[i] = rope[i].advance_by(Direction::North, 1),
-                        (0, -1) => (),
-                        (0, 0) => (),
-                        (0, 1) => (),
-                        (0, 2) => rope[i] = rope[i].advance_by(Direction::South, 1),
-
-                        (1, -2) => rope[i] = rope[i].advance_by(Direction::North, 1).advance_by(Direction::East, 1),
-                        (1, -1) => (),
-                        (1, 0) => (),
-                        (1, 1) => (),
-                        (1, 2) => rope[i] = rope[i].advance_by(Direction::South, 1).advance_by(Direction::East, 1),
-
-                        (2, -2) => rope[i] = rope[i].advance_by(Direction::East, 1).advance_by(Direction::North, 1),
-                        (2, -1) => rope[i] = rope[i].advance_by(Direction::East, 1).advance_by(Direction::North, 1),
-                        (2, 0) => rope[i] = rope[i].advance_by(Direction::East, 1),
-                        (2, 1) => rope[i] = rope[i].advance_by(Direction::East, 1).advance_by(Direction::South, 1),
-                        (2, 2) => rope[i] = rope[i].advance_by(Direction::East, 1).advance_by(Direction::South, 1),
-                        _ => unreachable!(),
+                    // Knots only move once they are no longer touching, one step towards the previous knot
+                    let delta = (rope[i - 1].x - rope[i].x, rope[i - 1].y - rope[i].y);
+                    if delta.0.abs() > 1 || delta.1.abs() > 1 {
+                        rope[i] = rope[i].advance8_by(Direction8::from_offset(delta).unwrap(), 1);
                     }
                 }
 
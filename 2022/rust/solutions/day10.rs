@@ -1,5 +1,8 @@
+use aoc_lib::cartesian::Position;
+use aoc_lib::ocr;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
+use itertools::Itertools;
 
 pub struct Solution10;
 impl Solution10 {
@@ -47,22 +50,20 @@ impl Solution for Solution10 {
     }
 
     fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
+        if is_sample {
+            return ProblemResult::NoSample;
+        }
+
         let mut instructions = Self::parse(input);
         instructions.insert(0, None); // Insert noop to get x reg during not after
 
-        // let x_history = Self::x_history(&instructions);
-        // for (cycle, x) in x_history.enumerate() {
-        //     let col = cycle as i32 % 40;
-        //     if col == 0 {
-        //         println!();
-        //     }
-        //     print!("{}", if (x % 40).abs_diff(col) <= 1 { '#' } else { '.' });
-        // }
-
-        if is_sample {
-            ProblemResult::NoSample
-        } else {
-            "BJFRHRFU".to_result()
-        }
+        // The sprite is 3 pixels wide, the CRT draws 40 pixels per row
+        let lit = Self::x_history(&instructions)
+            .take(240)
+            .enumerate()
+            .filter(|(cycle, x)| (x % 40).abs_diff(*cycle as i32 % 40) <= 1)
+            .map(|(cycle, _)| Position::new(cycle as i128 % 40, cycle as i128 / 40))
+            .collect_vec();
+        ocr::read_positions(&lit).unwrap().to_result()
     }
 }
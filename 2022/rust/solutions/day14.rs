@@ -43,7 +43,7 @@ impl Solution14 {
         let mut pos = Index::new(500, 0);
 
         loop {
-            if let Some(down) = pos.advance_check(Direction::South, cave.size) {
+            if let Some(down) = pos.advance_check(Direction::South, cave.size()) {
                 if *cave.get(&down) == '.' {
                     pos = down;
                     continue;
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-use aoc_lib::graph::Graph;
+use aoc_lib::graph::{Graph, PathFinding};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use itertools::Itertools;
@@ -42,11 +42,18 @@ impl Solution16 {
     }
 
     /// Reduce tunnel graph to interconnect all valves and skip jammed valves.
-    fn update_tunnel_network(tunnels: Tunnels, flow_map: &FlowMap, start_valve: Valve) -> Tunnels {
-        let shortest_paths = tunnels.floyd_warshall();
-        let valves = tunnels.vertices();
+    fn update_tunnel_network(mut tunnels: Tunnels, flow_map: &FlowMap, start_valve: Valve) -> Tunnels {
+        // Contract to working valves and the start first, as there are only a few of them
+        let working = tunnels
+            .vertices()
+            .into_iter()
+            .filter(|v| flow_map[v] != 0 || *v == start_valve)
+            .collect_vec();
+        let network = tunnels.contract(&working).graph;
+
+        let shortest_paths = network.floyd_warshall();
         let mut better_tunnels = Graph::empty();
-        for (a, b) in valves.into_iter().tuple_combinations() {
+        for (a, b) in working.into_iter().tuple_combinations() {
             // Only add edge if both ends are not jammed or either valve is the start.
             if (flow_map[&a] != 0 && (flow_map[&b] != 0 || b == start_valve))
                 || (flow_map[&b] != 0 && (flow_map[&a] != 0 || a == start_valve))
@@ -1,43 +1,22 @@
 use std::collections::{HashSet, VecDeque};
 
-use aoc_lib::algebra::Vec3;
-use aoc_lib::iterator::ParsedExt;
+use aoc_lib::cartesian::point::{Grid3, Point};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-use itertools::{iproduct, Itertools};
 
-type Cube = Vec3<i16>;
+type Cube = Point<3, i16>;
 
 pub struct Solution18;
 impl Solution18 {
     fn parse(input: ProblemInput) -> Vec<Cube> {
-        input
-            .lines()
-            .into_iter()
-            .map(|l| {
-                let (x, y, z) = l.split(',').parsed().collect_tuple().unwrap();
-                Vec3::new(x, y, z)
-            })
-            .collect()
-    }
-
-    fn deltas() -> [Cube; 6] {
-        [
-            Vec3::new(1, 0, 0),
-            Vec3::new(-1, 0, 0),
-            Vec3::new(0, 1, 0),
-            Vec3::new(0, -1, 0),
-            Vec3::new(0, 0, 1),
-            Vec3::new(0, 0, -1),
-        ]
+        input.lines().into_iter().map(|l| l.parse().unwrap()).collect()
     }
 
     fn count_sides(droplets: &[Cube], no_air: &HashSet<Cube>) -> u16 {
-        let deltas = Self::deltas();
         let mut sides = 0;
         for droplet in droplets {
-            for delta in &deltas {
-                if !no_air.contains(&(*droplet + *delta)) {
+            for side in droplet.von_neumann_neighbors() {
+                if !no_air.contains(&side) {
                     sides += 1;
                 }
             }
@@ -67,57 +46,35 @@ impl Solution for Solution18 {
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let droplets = Self::parse(input);
 
-        // Compute bounds
-        let (x_min, x_max) = droplets.iter().map(|c| c.x).minmax().into_option().unwrap();
-        let (y_min, y_max) = droplets.iter().map(|c| c.y).minmax().into_option().unwrap();
-        let (z_min, z_max) = droplets.iter().map(|c| c.z).minmax().into_option().unwrap();
-        let x_range = x_min..=x_max;
-        let y_range = y_min..=y_max;
-        let z_range = z_min..=z_max;
-
-        // Start with droplets as no air cubes
-        let mut no_air: HashSet<_> = droplets.iter().cloned().collect();
-        let deltas = Self::deltas();
-
-        for (x, y, z) in iproduct!(x_range.clone(), y_range.clone(), z_range.clone()) {
-            // If current cube is no air, skip
-            let center = Vec3::new(x, y, z);
-            if no_air.contains(&center) {
-                continue;
-            }
-
-            // Flood fill from current center
-            let mut queue = VecDeque::from([center]);
-            let mut pocket = HashSet::new();
-            let mut is_interior = true;
+        // Dense grid around the droplets, with one layer of air on each side
+        let (min, max) = Point::bounds(&droplets).unwrap();
+        let origin = min - Point([1; 3]);
+        let to_grid = |cube: Cube| Point((cube - origin).0.map(|c| c as usize));
 
-            while let Some(cube) = queue.pop_front() {
-                if !pocket.insert(cube) {
-                    continue;
-                }
+        let mut lava = Grid3::empty(to_grid(max + Point([2; 3])), false);
+        for droplet in &droplets {
+            lava.set(&to_grid(*droplet), true);
+        }
 
-                // Reached out of bounds, this is no pockets
-                if !x_range.contains(&cube.x) || !y_range.contains(&cube.y) || !z_range.contains(&cube.z) {
-                    is_interior = false;
-                    break;
-                }
+        // Flood fill the air reachable from the outside
+        let mut outside = lava.map_elements(|_| false);
+        let mut queue = VecDeque::from([Point::zero()]);
+        outside.set(&Point::zero(), true);
 
-                // Compute next frontiers
-                for delta in &deltas {
-                    let next = cube + *delta;
-                    if !no_air.contains(&next) {
-                        queue.push_back(next)
-                    }
+        while let Some(cube) = queue.pop_front() {
+            for next in lava.von_neumann_neighbors(&cube) {
+                if !lava.get(&next) && !outside.get(&next) {
+                    outside.set(&next, true);
+                    queue.push_back(next);
                 }
             }
-
-            // If filling ended because we reached a droplet on every front, we found a pocket
-            if is_interior {
-                no_air.extend(pocket);
-            }
         }
 
-        let sides = Self::count_sides(&droplets, &no_air);
+        let sides = droplets
+            .iter()
+            .flat_map(|droplet| droplet.von_neumann_neighbors())
+            .filter(|side| *outside.get(&to_grid(*side)))
+            .count() as u16;
         sides.to_result()
     }
 }
@@ -1,4 +1,5 @@
-use aoc_lib::cartesian::{Direction, Grid, Index, Size};
+use aoc_lib::cartesian::cube::CubeNet;
+use aoc_lib::cartesian::{Direction, Grid, Index};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use itertools::Itertools;
@@ -7,13 +8,15 @@ pub struct Solution22;
 impl Solution22 {
     /// Create big map and parse move list
     fn parse(input: ProblemInput) -> (Grid<char>, Vec<String>) {
-        let mut map = input.grid();
-        map.rows = map.rows.into_iter().take_while(|r| !r.is_empty()).collect();
-        let max_width = map.rows.iter().map(|r| r.len()).max().unwrap();
-        map.rows.iter_mut().for_each(|r| r.resize(max_width, ' '));
-        map.size = Size::new(max_width, map.rows.len());
-
+        // Map lines are padded to the same width
         let lines = input.lines();
+        let map = lines
+            .iter()
+            .take_while(|l| !l.is_empty())
+            .cloned()
+            .collect::<ProblemInput>()
+            .grid();
+
         let path = lines.last().unwrap();
         let moves = path
             .split_inclusive(['L', 'R'])
@@ -24,33 +27,6 @@ impl Solution22 {
 
         (map, moves)
     }
-
-    /// Indexing and positioning of the sides in the input
-    /// Sample:         Real:    12
-    ///           1              3
-    ///         234             54
-    ///          56             6             
-    fn offsets(sample: bool) -> [(usize, usize); 6] {
-        if sample {
-            [(0, 2), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)]
-        } else {
-            [(0, 1), (0, 2), (1, 1), (2, 1), (2, 0), (3, 0)]
-        }
-    }
-
-    /// Create 6 grids for each side based on offsets
-    fn split_grids(map: &Grid<char>, size: usize, sample: bool) -> [Grid<char>; 6] {
-        Self::offsets(sample).map(|(oj, oi)| {
-            Grid::new(
-                map.rows
-                    .iter()
-                    .skip(oj * size)
-                    .take(size)
-                    .map(|r| r.iter().copied().skip(oi * size).take(size).collect())
-                    .collect(),
-            )
-        })
-    }
 }
 
 impl Solution for Solution22 {
@@ -80,7 +56,7 @@ impl Solution for Solution22 {
                     let mut moves = 0;
                     let mut last_valid = idx;
                     while moves < tiles {
-                        let next = idx.advance_wrap(dir, map.size);
+                        let next = idx.advance_wrap(dir, map.size());
                         match map.get(&next) {
                             '.' => {
                                 idx = next;
@@ -111,88 +87,26 @@ impl Solution for Solution22 {
         pwd.to_result()
     }
 
-    fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
-        // Create grids
-        let (whole_map, path) = Self::parse(input);
-        let size = if is_sample { 4 } else { 50 };
-        let maps = Self::split_grids(&whole_map, size, is_sample);
+    fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
+        let (map, path) = Self::parse(input);
+        let cube = CubeNet::new(&map, |c| *c == ' ');
 
         // Move to start
-        let mut map_idx = 0;
+        let mut idx = cube.faces[0];
         let mut dir = Direction::East;
-        let mut idx = Index::new(0, 0);
 
         // Follow path
-        let last = size - 1;
         for step in path {
             match step.parse::<u16>() {
                 Ok(tiles) => {
                     for _ in 0..tiles {
-                        // Hardcoded edge transitions based on current side and current direction
-                        let (next_map, next_idx, next_dir) = match idx.advance_check(dir, maps[map_idx].size) {
-                            Some(next) => (map_idx, next, dir),
-                            None if is_sample => match (map_idx, dir) {
-                                (0, Direction::North) => (1, Index::new(last - idx.i, 0), Direction::South),
-                                (0, Direction::East) => (5, Index::new(last, last - idx.j), Direction::West),
-                                (0, Direction::South) => (3, Index::new(idx.i, 0), Direction::South),
-                                (0, Direction::West) => (2, Index::new(idx.j, 0), Direction::South),
-                                (1, Direction::North) => (0, Index::new(last - idx.i, 0), Direction::South),
-                                (1, Direction::East) => (2, Index::new(0, idx.j), Direction::East),
-                                (1, Direction::South) => (4, Index::new(last - idx.i, last), Direction::North),
-                                (1, Direction::West) => (5, Index::new(last - idx.j, last), Direction::North),
-                                (2, Direction::North) => (0, Index::new(0, idx.i), Direction::East),
-                                (2, Direction::East) => (3, Index::new(0, idx.j), Direction::East),
-                                (2, Direction::South) => (4, Index::new(0, last - idx.i), Direction::East),
-                                (2, Direction::West) => (1, Index::new(last, idx.j), Direction::West),
-                                (3, Direction::North) => (0, Index::new(idx.i, last), Direction::North),
-                                (3, Direction::East) => (5, Index::new(last - idx.j, 0), Direction::South),
-                                (3, Direction::South) => (4, Index::new(idx.i, 0), Direction::South),
-                                (3, Direction::West) => (2, Index::new(last, idx.j), Direction::West),
-                                (4, Direction::North) => (3, Index::new(idx.i, last), Direction::North),
-                                (4, Direction::East) => (5, Index::new(0, idx.j), Direction::East),
-                                (4, Direction::South) => (1, Index::new(last - idx.i, last), Direction::North),
-                                (4, Direction::West) => (2, Index::new(last - idx.j, last), Direction::North),
-                                (5, Direction::North) => (3, Index::new(last, last - idx.i), Direction::West),
-                                (5, Direction::East) => (0, Index::new(last, last - idx.j), Direction::West),
-                                (5, Direction::South) => (1, Index::new(0, last - idx.i), Direction::East),
-                                (5, Direction::West) => (4, Index::new(last, idx.j), Direction::West),
-                                _ => unreachable!(),
-                            },
-                            None => match (map_idx, dir) {
-                                (0, Direction::North) => (5, Index::new(0, idx.i), Direction::East),
-                                (0, Direction::East) => (1, Index::new(0, idx.j), Direction::East),
-                                (0, Direction::South) => (2, Index::new(idx.i, 0), Direction::South),
-                                (0, Direction::West) => (4, Index::new(0, last - idx.j), Direction::East),
-                                (1, Direction::North) => (5, Index::new(idx.i, last), Direction::North),
-                                (1, Direction::East) => (3, Index::new(last, last - idx.j), Direction::West),
-                                (1, Direction::South) => (2, Index::new(last, idx.i), Direction::West),
-                                (1, Direction::West) => (0, Index::new(last, idx.j), Direction::West),
-                                (2, Direction::North) => (0, Index::new(idx.i, last), Direction::North),
-                                (2, Direction::East) => (1, Index::new(idx.j, last), Direction::North),
-                                (2, Direction::South) => (3, Index::new(idx.i, 0), Direction::South),
-                                (2, Direction::West) => (4, Index::new(idx.j, 0), Direction::South),
-                                (3, Direction::North) => (2, Index::new(idx.i, last), Direction::North),
-                                (3, Direction::East) => (1, Index::new(last, last - idx.j), Direction::West),
-                                (3, Direction::South) => (5, Index::new(last, idx.i), Direction::West),
-                                (3, Direction::West) => (4, Index::new(last, idx.j), Direction::West),
-                                (4, Direction::North) => (2, Index::new(0, idx.i), Direction::East),
-                                (4, Direction::East) => (3, Index::new(0, idx.j), Direction::East),
-                                (4, Direction::South) => (5, Index::new(idx.i, 0), Direction::South),
-                                (4, Direction::West) => (0, Index::new(0, last - idx.j), Direction::East),
-                                (5, Direction::North) => (4, Index::new(idx.i, last), Direction::North),
-                                (5, Direction::East) => (3, Index::new(idx.j, last), Direction::North),
-                                (5, Direction::South) => (1, Index::new(idx.i, 0), Direction::South),
-                                (5, Direction::West) => (0, Index::new(idx.j, 0), Direction::South),
-                                _ => unreachable!(),
-                            },
-                        };
+                        let (next_idx, next_dir) = cube.advance(&idx, dir);
 
                         // Hit wall
-                        if *maps[next_map].get(&next_idx) == '#' {
+                        if *map.get(&next_idx) == '#' {
                             break;
                         }
 
-                        map_idx = next_map;
                         idx = next_idx;
                         dir = next_dir;
                     }
@@ -206,8 +120,7 @@ impl Solution for Solution22 {
         }
 
         // Compute password
-        let (oj, oi) = Self::offsets(is_sample)[map_idx];
-        let pwd = (1000 * ((size * oj) + idx.j + 1)) + (4 * ((size * oi) + idx.i + 1)) + (dir as usize + 3) % 4;
+        let pwd = (1000 * (idx.j + 1)) + (4 * (idx.i + 1)) + (dir as usize + 3) % 4;
         pwd.to_result()
     }
 }
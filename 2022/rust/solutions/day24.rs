@@ -1,4 +1,4 @@
-use aoc_lib::cartesian::{Direction, Grid, Index};
+use aoc_lib::cartesian::{Direction, Grid, GridCell, Index};
 use aoc_lib::graph::{DynamicGraph, PathFinding};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -10,18 +10,39 @@ enum ValleyTile {
     Blizzards(Vec<Direction>),
 }
 
-pub struct Solution24;
-impl Solution24 {
-    fn parse(input: ProblemInput) -> (Grid<ValleyTile>, Index, Index) {
-        let valley = input.grid().map_elements(|&c| match c {
+impl GridCell for ValleyTile {
+    fn from_char(c: char) -> Self {
+        match c {
             '.' => ValleyTile::Ground,
             '#' => ValleyTile::Wall,
             _ => ValleyTile::Blizzards(vec![c.try_into().unwrap()]),
-        });
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            ValleyTile::Ground => '.',
+            ValleyTile::Wall => '#',
+            ValleyTile::Blizzards(blizzards) => match blizzards[..] {
+                [Direction::North] => '^',
+                [Direction::East] => '>',
+                [Direction::South] => 'v',
+                [Direction::West] => '<',
+                _ => char::from_digit(blizzards.len() as u32, 10).unwrap_or('*'),
+            },
+        }
+    }
+}
 
-        let last_row = valley.size.height - 1;
-        let start_i = valley.rows[0].iter().position(|c| *c == ValleyTile::Ground).unwrap();
-        let goal_i = valley.rows[last_row]
+pub struct Solution24;
+impl Solution24 {
+    fn parse(input: ProblemInput) -> (Grid<ValleyTile>, Index, Index) {
+        let valley = input.grid_of::<ValleyTile>();
+
+        let last_row = valley.size().height - 1;
+        let start_i = valley.row(0).iter().position(|c| *c == ValleyTile::Ground).unwrap();
+        let goal_i = valley
+            .row(last_row)
             .iter()
             .position(|c| *c == ValleyTile::Ground)
             .unwrap();
@@ -42,7 +63,7 @@ impl Solution24 {
 
                 // Find possible next moves
                 let mut children = Vec::new();
-                let mut possible = index.von_neumann_neighbors(valley.size);
+                let mut possible = index.von_neumann_neighbors(valley.size());
                 possible.push(*index);
                 for nb in possible {
                     if matches!(valley.get(&nb), ValleyTile::Ground) {
@@ -55,7 +76,7 @@ impl Solution24 {
     }
 
     fn move_blizzards(current: &Grid<ValleyTile>) -> Grid<ValleyTile> {
-        let mut next_map = Grid::empty(current.size, ValleyTile::Ground);
+        let mut next_map = Grid::empty(current.size(), ValleyTile::Ground);
 
         for (idx, tile) in current.enumerate() {
             match tile {
@@ -65,14 +86,13 @@ impl Solution24 {
                     for &dir in dirs {
                         let mut next = idx.advance(dir);
                         if matches!(current.get(&next), ValleyTile::Wall) {
-                            next = next.advance_wrap(dir, current.size).advance(dir);
+                            next = next.advance_wrap(dir, current.size()).advance(dir);
                         }
-                        let new_tile = match next_map.get(&next) {
-                            ValleyTile::Ground => ValleyTile::Blizzards(vec![dir]),
-                            ValleyTile::Blizzards(dirs) => ValleyTile::Blizzards([dirs.clone(), vec![dir]].concat()),
+                        match next_map.get_mut(&next) {
+                            tile @ ValleyTile::Ground => *tile = ValleyTile::Blizzards(vec![dir]),
+                            ValleyTile::Blizzards(dirs) => dirs.push(dir),
                             ValleyTile::Wall => unreachable!(),
-                        };
-                        next_map.set(&next, new_tile);
+                        }
                     }
                 }
             }
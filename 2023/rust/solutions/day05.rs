@@ -1,37 +1,10 @@
 use itertools::Itertools;
 
+use aoc_lib::interval::{Interval, OffsetMap, RangeSet};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-struct MapEntry {
-    dst: u64,
-    src: u64,
-    len: u64,
-}
-
-impl MapEntry {
-    fn in_range(&self, value: u64) -> bool {
-        (self.src..(self.src + self.len)).contains(&value)
-    }
-
-    fn map(&self, value: u64) -> u64 {
-        self.dst + (value - self.src)
-    }
-}
-
-#[derive(Clone, Copy)]
-struct SeedRange {
-    first: u64,
-    len: u64,
-}
-
-impl SeedRange {
-    fn last(&self) -> u64 {
-        self.first + self.len - 1
-    }
-}
-
 type Seed = u64;
-type Map = Vec<MapEntry>;
+type Map = OffsetMap<u64>;
 
 pub struct Solution05;
 
@@ -48,12 +21,12 @@ impl Solution05 {
             .collect_vec();
 
         let mut maps = vec![];
-        let mut current_map = vec![];
+        let mut current_map = Map::new();
 
         for line in input.lines().iter().skip(2) {
             if line.chars().all(char::is_whitespace) {
                 maps.push(current_map);
-                current_map = vec![];
+                current_map = Map::new();
                 continue;
             }
 
@@ -68,94 +41,22 @@ impl Solution05 {
                 .collect_tuple()
                 .unwrap();
             let (d, s, l) = values;
-            current_map.push(MapEntry { dst: d, src: s, len: l });
+            current_map.insert(Interval::with_len(s, l), d);
         }
 
         maps.push(current_map);
         (seeds, maps)
     }
 
-    fn parse2(&self, lines: ProblemInput) -> (Vec<SeedRange>, Vec<Map>) {
+    fn parse2(&self, lines: ProblemInput) -> (RangeSet<Seed>, Vec<Map>) {
         let (seeds, maps) = self.parse(lines);
         let seed_ranges = seeds
             .into_iter()
             .tuples()
-            .map(|(s, l)| SeedRange { first: s, len: l })
+            .map(|(start, len)| Interval::with_len(start, len))
             .collect();
         (seed_ranges, maps)
     }
-
-    fn apply_map(&self, value: Seed, map: &Map) -> Seed {
-        map.iter()
-            .find(|entry| entry.in_range(value))
-            .map_or(value, |entry| entry.map(value))
-    }
-
-    fn apply_map2(&self, values: Vec<SeedRange>, map: &Map) -> Vec<SeedRange> {
-        let mut mapped_values = vec![];
-        let mut remaining_values = values;
-
-        loop {
-            let mut new_values = vec![];
-
-            for value in remaining_values.iter() {
-                let mut is_mapped = false;
-                for entry in map {
-                    let init_fits = entry.in_range(value.first);
-                    let tail_fits = entry.in_range(value.last());
-
-                    if init_fits && tail_fits {
-                        mapped_values.push(SeedRange {
-                            first: entry.map(value.first),
-                            len: value.len,
-                        });
-                        is_mapped = true;
-                        break;
-                    } else if init_fits {
-                        let mapped_len = (entry.src + entry.len) - value.first;
-                        mapped_values.push(SeedRange {
-                            first: entry.map(value.first),
-                            len: mapped_len,
-                        });
-                        new_values.push(SeedRange {
-                            first: value.first + mapped_len,
-                            len: value.len - mapped_len,
-                        });
-                        is_mapped = true;
-                        break;
-                    } else if tail_fits {
-                        let mapped_len = (value.first + value.len) - entry.src;
-                        mapped_values.push(SeedRange {
-                            first: entry.dst,
-                            len: mapped_len,
-                        });
-                        new_values.push(SeedRange {
-                            first: value.first,
-                            len: value.len - mapped_len,
-                        });
-                        is_mapped = true;
-                        break;
-                    }
-
-                    // Forgot to check if middle fits
-                    // (so that the middle is mapped and init and tail have to be rechecked)
-                    // Apparently thats not a problem
-                }
-
-                if !is_mapped {
-                    mapped_values.push(*value);
-                }
-            }
-
-            if new_values.is_empty() {
-                break;
-            }
-
-            remaining_values = new_values;
-        }
-
-        mapped_values
-    }
 }
 
 impl Solution for Solution05 {
@@ -172,7 +73,7 @@ impl Solution for Solution05 {
         let (seeds, maps) = self.parse(input);
         seeds
             .iter()
-            .map(|s| maps.iter().fold(*s, |acc, m| self.apply_map(acc, m)))
+            .map(|s| maps.iter().fold(*s, |acc, m| m.apply(acc)))
             .min()
             .unwrap()
             .to_result()
@@ -180,12 +81,7 @@ impl Solution for Solution05 {
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let (seeds, maps) = self.parse2(input);
-        seeds
-            .into_iter()
-            .flat_map(|s| maps.iter().fold(vec![s], |acc, m| self.apply_map2(acc, m)))
-            .map(|sr| sr.first)
-            .min()
-            .unwrap()
-            .to_result()
+        let locations = maps.iter().fold(seeds, |acc, m| m.apply_set(&acc));
+        locations.min().unwrap().to_result()
     }
 }
@@ -1,8 +1,8 @@
 use aoc_lib::prelude::solution::Solution;
 
-use aoc_lib::cartesian::{Direction, Grid, Index};
+use aoc_lib::cartesian::polygon::Polygon;
+use aoc_lib::cartesian::{Direction, Grid, Index, Position};
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-use itertools::Itertools;
 
 type CharGrid = Grid<char>;
 
@@ -26,7 +26,7 @@ impl Solution10 {
     }
 
     fn find_path_beginning(&self, start: Index, grid: &CharGrid) -> (Index, Direction) {
-        if let Some(up) = start.advance_check(Direction::North, grid.size) {
+        if let Some(up) = start.advance_check(Direction::North, grid.size()) {
             let symbol = grid.get(&up);
             match symbol {
                 '|' => return (up, Direction::North),
@@ -36,7 +36,7 @@ impl Solution10 {
             }
         }
 
-        if let Some(down) = start.advance_check(Direction::South, grid.size) {
+        if let Some(down) = start.advance_check(Direction::South, grid.size()) {
             let symbol = grid.get(&down);
             match symbol {
                 '|' => return (down, Direction::South),
@@ -46,7 +46,7 @@ impl Solution10 {
             }
         }
 
-        if let Some(left) = start.advance_check(Direction::West, grid.size) {
+        if let Some(left) = start.advance_check(Direction::West, grid.size()) {
             let symbol = grid.get(&left);
             match symbol {
                 '-' => return (left, Direction::West),
@@ -56,7 +56,7 @@ impl Solution10 {
             }
         }
 
-        if let Some(right) = start.advance_check(Direction::East, grid.size) {
+        if let Some(right) = start.advance_check(Direction::East, grid.size()) {
             let symbol = grid.get(&right);
             match symbol {
                 '-' => return (right, Direction::East),
@@ -92,23 +92,10 @@ impl Solution10 {
         (next_pos, next_dir)
     }
 
-    fn compute_area(&self, grid: &CharGrid, loop_path: Vec<Index>) -> u64 {
-        let mut vertices = loop_path
-            .iter()
-            .filter(|idx| "SFL7J".contains(*grid.get(idx)))
-            .collect_vec();
-        vertices.push(vertices[0]);
-
-        // Shoelace formula
-        let mut area_inside = 0i64;
-        for (v, nv) in vertices.iter().zip(vertices.iter().skip(1)) {
-            area_inside += (v.j as i64 + nv.j as i64) * (v.i as i64 - nv.i as i64);
-        }
-        area_inside /= 2;
-
-        // Pick's theorem
-        let boundary = loop_path.len() as u64;
-        area_inside.unsigned_abs() - boundary / 2 + 1
+    fn compute_area(&self, loop_path: Vec<Index>) -> u64 {
+        // The loop cells are the boundary points, straight pipes are just collinear vertices
+        let polygon = Polygon::new(loop_path.into_iter().map(Position::from).collect());
+        polygon.interior_points() as u64
     }
 }
 
@@ -131,6 +118,6 @@ impl Solution for Solution10 {
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let grid = self.parse(input);
         let loop_path = self.find_loop(&grid);
-        self.compute_area(&grid, loop_path).to_result()
+        self.compute_area(loop_path).to_result()
     }
 }
@@ -1,7 +1,7 @@
-use aoc_lib::cartesian::Grid;
+use aoc_lib::cartesian::{Direction, Grid};
 use itertools::Itertools;
 
-use std::{collections::HashMap, iter};
+use std::collections::HashMap;
 
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -17,75 +17,45 @@ impl Solution14 {
     fn eval_load(grid: &CharGrid) -> usize {
         grid.enumerate()
             .filter(|(_, c)| **c == 'O')
-            .map(|(idx, _)| (grid.size.height - idx.j))
+            .map(|(idx, _)| grid.size().height - idx.j)
             .sum()
     }
 
     fn cycle(grid: &CharGrid) -> CharGrid {
-        let tilted_north = Self::tilt(grid, true, false);
-        let tilted_west = Self::tilt(&tilted_north, false, false);
-        let tilted_south = Self::tilt(&tilted_west, true, true);
-        Self::tilt(&tilted_south, false, true)
-    }
-
-    fn tilt(grid: &CharGrid, transpose: bool, reverse: bool) -> CharGrid {
-        let grid_t = grid.transpose();
-        let grid_iter = match transpose {
-            false => grid,
-            true => &grid_t,
-        };
-
-        let mapped_grid = Grid::new(match reverse {
-            false => grid_iter.rows.iter().map(|l| Self::tilt_line_left(l)).collect_vec(),
-            true => grid_iter.rows.iter().map(|l| Self::tilt_line_right(l)).collect_vec(),
-        });
-
-        match transpose {
-            false => mapped_grid,
-            true => mapped_grid.transpose(),
+        let mut grid = grid.clone();
+        for dir in [Direction::North, Direction::West, Direction::South, Direction::East] {
+            Self::tilt(&mut grid, dir);
         }
+        grid
     }
 
-    fn tilt_line_left(line: &[char]) -> Vec<char> {
-        let mut cube_pos = line
-            .iter()
-            .positions(|c| *c == '#')
-            .map(|i| i.try_into().unwrap())
-            .collect_vec();
-        cube_pos.insert(0, -1i32);
-        cube_pos.push(line.len() as i32);
-
-        let section_lengths = cube_pos
-            .iter()
-            .skip(1)
-            .zip(cube_pos.iter())
-            .map(|(nxt, cur)| nxt - cur - 1)
-            .collect_vec();
-
-        let rounds_per_section = line
-            .split(|c| *c == '#')
-            .map(|section| section.iter().filter(|c| **c == 'O').count() as i32)
-            .collect_vec();
-
-        Itertools::intersperse(
-            section_lengths
-                .iter()
-                .zip_eq(rounds_per_section.iter())
-                .map(|(&sec_len, &rounds)| {
-                    iter::repeat_n('O', rounds as usize)
-                        .chain(iter::repeat_n('.', (sec_len - rounds) as usize))
-                        .collect_vec()
-                }),
-            vec!['#'],
-        )
-        .flatten()
-        .collect_vec()
+    /// Tilts grid in place, by rolling each column / row towards the given direction
+    fn tilt(grid: &mut CharGrid, dir: Direction) {
+        let width = grid.size().width;
+        match dir {
+            Direction::North => (0..width).for_each(|i| Self::roll(grid.column_mut(i))),
+            Direction::South => (0..width).for_each(|i| Self::roll(grid.column_mut(i).rev())),
+            Direction::West => grid.rows_mut().for_each(|row| Self::roll(row.iter_mut())),
+            Direction::East => grid.rows_mut().for_each(|row| Self::roll(row.iter_mut().rev())),
+            Direction::None => (),
+        }
     }
 
-    fn tilt_line_right(line: &[char]) -> Vec<char> {
-        let rev_col = line.iter().rev().cloned().collect_vec();
-        let rev_tilt = Self::tilt_line_left(&rev_col);
-        rev_tilt.into_iter().rev().collect_vec()
+    /// Rolls all round rocks in the line to its front, until they hit a cube rock
+    fn roll<'a>(line: impl Iterator<Item = &'a mut char>) {
+        let mut line = line.collect_vec();
+        let mut free = 0;
+        for k in 0..line.len() {
+            match *line[k] {
+                '#' => free = k + 1,
+                'O' => {
+                    *line[k] = '.';
+                    *line[free] = 'O';
+                    free += 1;
+                }
+                _ => (),
+            }
+        }
     }
 }
 
@@ -100,9 +70,9 @@ impl Solution for Solution14 {
     }
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
-        let grid = self.parse(input);
-        let tilted = Self::tilt(&grid, true, false); // North
-        Self::eval_load(&tilted).to_result()
+        let mut grid = self.parse(input);
+        Self::tilt(&mut grid, Direction::North);
+        Self::eval_load(&grid).to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
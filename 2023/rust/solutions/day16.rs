@@ -1,4 +1,4 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
 
 use aoc_lib::cartesian::{Direction, Grid, Index};
 use aoc_lib::prelude::solution::Solution;
@@ -13,8 +13,10 @@ struct Beam {
 pub struct Solution16;
 impl Solution16 {
     fn traverse(grid: &Grid<char>, beam_start: Beam) -> usize {
-        let mut beams: VecDeque<Beam> = VecDeque::from([beam_start.clone()]);
-        let mut beam_history = HashSet::from([beam_start]);
+        // Directions of all beams seen per tile, as bitmask
+        let mut beam_history = Grid::empty(grid.size(), 0u8);
+        beam_history.set(&beam_start.pos, 1 << beam_start.dir as u8);
+        let mut beams: VecDeque<Beam> = VecDeque::from([beam_start]);
 
         while let Some(b) = beams.pop_front() {
             let new_dirs = match grid.get(&b.pos) {
@@ -47,19 +49,18 @@ impl Solution16 {
             };
 
             for dir in new_dirs {
-                if let Some(next_pos) = b.pos.advance_check(dir, grid.size) {
-                    let new_beam = Beam { pos: next_pos, dir };
-                    if !beam_history.contains(&new_beam) {
-                        beam_history.insert(new_beam.clone());
-                        beams.push_back(new_beam);
+                if let Some(next_pos) = b.pos.advance_check(dir, grid.size()) {
+                    let seen_dirs = beam_history.get_mut(&next_pos);
+                    if *seen_dirs & (1 << dir as u8) == 0 {
+                        *seen_dirs |= 1 << dir as u8;
+                        beams.push_back(Beam { pos: next_pos, dir });
                     }
                 }
             }
         }
 
-        let energized: HashSet<Index> = beam_history.iter().map(|b| b.pos).collect();
-        // grid.print(|idx, _| if energized.contains(&idx) { "#" } else { "." });
-        energized.len()
+        // beam_history.print(|_, dirs| if *dirs != 0 { "#" } else { "." });
+        beam_history.iter().filter(|dirs| **dirs != 0).count()
     }
 }
 
@@ -86,20 +87,20 @@ impl Solution for Solution16 {
         let grid = input.grid();
 
         let mut start_beams = Vec::new();
-        start_beams.extend((0..grid.size.height).map(|y| Beam {
+        start_beams.extend((0..grid.size().height).map(|y| Beam {
             pos: Index::new(0, y),
             dir: Direction::East,
         }));
-        start_beams.extend((0..grid.size.height).map(|y| Beam {
-            pos: Index::new(grid.size.width - 1, y),
+        start_beams.extend((0..grid.size().height).map(|y| Beam {
+            pos: Index::new(grid.size().width - 1, y),
             dir: Direction::West,
         }));
-        start_beams.extend((0..grid.size.width).map(|x| Beam {
+        start_beams.extend((0..grid.size().width).map(|x| Beam {
             pos: Index::new(x, 0),
             dir: Direction::South,
         }));
-        start_beams.extend((0..grid.size.width).map(|x| Beam {
-            pos: Index::new(x, grid.size.height - 1),
+        start_beams.extend((0..grid.size().width).map(|x| Beam {
+            pos: Index::new(x, grid.size().height - 1),
             dir: Direction::North,
         }));
 
@@ -1,5 +1,5 @@
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
+use std::collections::BinaryHeap;
 use std::fmt::Debug;
 
 use aoc_lib::cartesian::{Direction, Grid, Index, Size};
@@ -15,7 +15,7 @@ impl Solution17 {
             .map_elements(|elem| elem.to_string().parse::<u32>().unwrap());
 
         LatticeGraph {
-            size: grid.size,
+            size: grid.size(),
             weights: grid,
         }
     }
@@ -89,7 +89,9 @@ impl LatticeGraph {
         min_straight: usize,
         max_straight: usize,
     ) -> Option<QueueElement> {
-        let mut visited: HashSet<Vertex> = HashSet::new();
+        // Visited vertices per position, as bitmask over their straight movement
+        assert!(max_straight < 16);
+        let mut visited = Grid::empty(self.size, 0u64);
         let mut queue: BinaryHeap<QueueElement> = BinaryHeap::from([QueueElement {
             heat: 0,
             vertex: Vertex {
@@ -103,10 +105,15 @@ impl LatticeGraph {
             if u == goal {
                 return Some(state);
             }
-            if visited.contains(&state.vertex) {
+            let bit = match state.vertex.straight {
+                Some((dir, count)) => 1 << (1 + dir as usize * 15 + count),
+                None => 1,
+            };
+            let visited_at = visited.get_mut(&u);
+            if *visited_at & bit != 0 {
                 continue;
             }
-            visited.insert(state.vertex.clone());
+            *visited_at |= bit;
 
             for (v, weight) in self.get_neighbors(&state.vertex, min_straight, max_straight) {
                 let new_heat = state.heat + weight;
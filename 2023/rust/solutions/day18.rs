@@ -1,3 +1,4 @@
+use aoc_lib::cartesian::polygon::Polygon;
 use aoc_lib::cartesian::{Direction, Position};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
@@ -44,29 +45,9 @@ impl Solution18 {
             .collect_vec()
     }
 
-    fn find_vertices(edges: &[(Direction, usize)]) -> Vec<Position> {
-        let mut vertex = Position::zero();
-        let mut vertices = Vec::from([vertex]);
-        for (dir, count) in edges {
-            vertex = vertex.advance_by(*dir, *count as i128);
-            vertices.push(vertex);
-        }
-        vertices
-    }
-
-    fn compute_area(vertices: &[Position], edges: &[(Direction, usize)]) -> u128 {
-        let boundary = edges.iter().fold(0, |acc, (_, l)| acc + *l as u128);
-
-        // Shoelace formula
-        let mut area_inside = 0i128;
-        for (v, nv) in vertices.iter().zip(vertices.iter().skip(1)) {
-            area_inside += (v.y + nv.y) * (v.x - nv.x);
-        }
-        area_inside /= 2;
-
-        // Pick's theorem
-        let inside = area_inside as u128 - boundary / 2 + 1;
-        boundary + inside
+    fn lagoon_size(edges: &[(Direction, usize)]) -> u128 {
+        let moves = edges.iter().map(|(dir, len)| (*dir, *len as i128));
+        Polygon::from_moves(Position::zero(), moves).lattice_points()
     }
 }
 
@@ -82,13 +63,11 @@ impl Solution for Solution18 {
 
     fn solve_version01(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let edges = Self::parse(input);
-        let vertices = Self::find_vertices(&edges);
-        Self::compute_area(&vertices, &edges).to_result()
+        Self::lagoon_size(&edges).to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, _is_sample: bool) -> ProblemResult {
         let edges = Self::parse2(input);
-        let vertices = Self::find_vertices(&edges);
-        Self::compute_area(&vertices, &edges).to_result()
+        Self::lagoon_size(&edges).to_result()
     }
 }
@@ -1,9 +1,9 @@
 use std::collections::HashSet;
 
-use aoc_lib::cartesian::{Grid, Index, Position};
+use aoc_lib::cartesian::grid_graph::GridGraph;
+use aoc_lib::cartesian::{Grid, Index};
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
-use itertools::Itertools;
 
 type BoolGrid = Grid<bool>;
 
@@ -16,22 +16,16 @@ impl Solution21 {
         (bool_grid, start)
     }
 
-    fn walk(grid: &BoolGrid, current: &[Position], steps: usize) -> Vec<Position> {
-        let mut positions = current.iter().cloned().collect_vec();
-        for _ in 0..steps {
-            let mut new_positions = HashSet::new();
-            for pos in positions {
-                let reached = pos
-                    .von_neumann_neighbors(1)
-                    .into_iter()
-                    .filter(|p| *grid.get(&p.wrap_modular(grid.size)));
-                new_positions.extend(reached);
-            }
-
-            positions = new_positions.into_iter().collect();
-        }
-
-        positions
+    /// Number of plots on the infinitely tiled map which can be reached in exactly the given number of steps.
+    /// As every step changes the parity of the position, these are the ones with a distance of the same parity.
+    fn reachable(grid: &BoolGrid, start: &Index, steps: usize) -> usize {
+        GridGraph::new(grid)
+            .passable(|_, to| *to)
+            .tiled()
+            .distances(&(*start).into(), steps)
+            .into_values()
+            .filter(|dist| dist % 2 == steps % 2)
+            .count()
     }
 
     #[allow(dead_code)]
@@ -59,8 +53,7 @@ impl Solution for Solution21 {
         let (grid, start) = Self::parse(input);
         let steps = if is_sample { 6 } else { 64 };
 
-        let plots = Self::walk(&grid, &[start.into()], steps);
-        plots.len().to_result()
+        Self::reachable(&grid, &start, steps).to_result()
     }
 
     fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult {
@@ -79,14 +72,10 @@ impl Solution for Solution21 {
         // step count.
 
         // Thus compute the first three times the borders are crossed
-        // Note: This takes like 20 secs
-        let pos0 = Self::walk(&grid, &[start.into()], 65);
-        let pos1 = Self::walk(&grid, &pos0, 131);
-        let pos2 = Self::walk(&grid, &pos1, 131);
+        let [y0, y1, y2] = [65, 196, 327].map(|steps| Self::reachable(&grid, &start, steps) as i128);
 
         // Collect the points for the parabola (note xs = (65, 196, 327) works fine too, then x = steps)
         let (x0, x1, x2) = (0, 1, 2);
-        let (y0, y1, y2) = (pos0.len() as i128, pos1.len() as i128, pos2.len() as i128);
 
         // Define x and compute y with lagranges interpolation
         let x = steps / 131;
@@ -1,24 +1,11 @@
 use std::collections::{BinaryHeap, HashMap, HashSet};
-use std::fmt::{Debug, Display};
-use std::hash::Hash;
 
-use aoc_lib::iterator::ParsedExt;
+use aoc_lib::cartesian::point::Point;
 use aoc_lib::prelude::solution::Solution;
 use aoc_lib::prelude::types::{ProblemInput, ProblemResult, ToResult};
 use itertools::Itertools;
 
-#[derive(PartialEq, Debug, Eq, Hash, Clone)]
-struct Position3 {
-    x: usize,
-    y: usize,
-    z: usize,
-}
-
-impl Display for Position3 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{0},{1},{2}", self.x, self.y, self.z)
-    }
-}
+type Position3 = Point<3, usize>;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct Brick {
@@ -31,20 +18,12 @@ struct Brick {
 
 impl Brick {
     fn lower(&mut self) {
-        self.from = Position3 {
-            x: self.from.x,
-            y: self.from.y,
-            z: self.from.z - 1,
-        };
-        self.to = Position3 {
-            x: self.to.x,
-            y: self.to.y,
-            z: self.to.z - 1,
-        };
+        self.from[2] -= 1;
+        self.to[2] -= 1;
     }
 
     fn on_ground(&self) -> bool {
-        self.from.z == 1
+        self.from.z() == 1
     }
 }
 
@@ -57,22 +36,18 @@ impl Solution22 {
             .enumerate()
             .map(|(i, line)| {
                 let (start, end) = line.split_once('~').unwrap();
-                let (xs, ys, zs) = start.split(',').parsed::<usize>().collect_tuple().unwrap();
-                let (xe, ye, ze) = end.split(',').parsed::<usize>().collect_tuple().unwrap();
-
-                let (x1, x2) = (xs.min(xe), xs.max(xe));
-                let (y1, y2) = (ys.min(ye), ys.max(ye));
-                let (z1, z2) = (zs.min(ze), zs.max(ze));
+                let start: Position3 = start.parse().unwrap();
+                let end: Position3 = end.parse().unwrap();
 
                 Brick {
                     id: i,
-                    from: Position3 { x: x1, y: y1, z: z1 },
-                    to: Position3 { x: x2, y: y2, z: z2 },
+                    from: start.component_min(&end),
+                    to: start.component_max(&end),
                     supported_by: Vec::new(),
                     supports: Vec::new(),
                 }
             })
-            .sorted_by_key(|b| b.from.z)
+            .sorted_by_key(|b| b.from.z())
             .collect_vec()
     }
 
@@ -83,10 +58,10 @@ impl Solution22 {
 
         for fb in bricks.iter_mut() {
             while !fb.on_ground() {
-                if let Some(lbs) = landed_bricks.get(&(fb.from.z - 1)) {
+                if let Some(lbs) = landed_bricks.get(&(fb.from.z() - 1)) {
                     for lb in lbs {
-                        if (fb.from.x <= lb.to.x && lb.from.x <= fb.to.x)
-                            && (fb.from.y <= lb.to.y && lb.from.y <= fb.to.y)
+                        if (fb.from.x() <= lb.to.x() && lb.from.x() <= fb.to.x())
+                            && (fb.from.y() <= lb.to.y() && lb.from.y() <= fb.to.y())
                         {
                             fb.supported_by.push(lb.id);
                             support_lookup.entry(lb.id).or_default().push(fb.id);
@@ -101,7 +76,7 @@ impl Solution22 {
                 fb.lower();
             }
 
-            landed_bricks.entry(fb.to.z).or_default().push(fb.clone());
+            landed_bricks.entry(fb.to.z()).or_default().push(fb.clone());
         }
 
         // Double sided lookup in brick, second direction has to be done afterwards
@@ -119,14 +94,14 @@ impl Solution22 {
         let mut disintegration = HashMap::new();
         for brick in bricks.iter() {
             let mut chain = HashSet::from([brick.id]);
-            let mut to_disintegrate = BinaryHeap::from([(-(brick.to.z as i32), brick.id)]);
+            let mut to_disintegrate = BinaryHeap::from([(-(brick.to.z() as i32), brick.id)]);
             while let Some((_, c)) = to_disintegrate.pop() {
                 let current_brick = brick_lookup.get(&c).unwrap();
                 for ab in current_brick.supports.iter() {
                     let above_brick = brick_lookup.get(ab).unwrap();
                     if above_brick.supported_by.iter().all(|sb| chain.contains(sb)) {
                         chain.insert(above_brick.id);
-                        to_disintegrate.push((-(above_brick.to.z as i32), above_brick.id));
+                        to_disintegrate.push((-(above_brick.to.z() as i32), above_brick.id));
                     }
                 }
             }
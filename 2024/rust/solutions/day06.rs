@@ -16,7 +16,7 @@ impl Solution06 {
                 return (visited.into_iter().map(|(idx, _)| idx).unique().collect_vec(), true);
             }
 
-            let next = match guard.advance_check(dir, map.size) {
+            let next = match guard.advance_check(dir, map.size()) {
                 Some(pos) => pos,
                 None => return (visited.into_iter().map(|(idx, _)| idx).unique().collect_vec(), false),
             };
@@ -5,7 +5,10 @@ pub mod algo;
 pub mod cartesian;
 pub mod grammar;
 pub mod graph;
+pub mod interval;
 pub mod iterator;
 pub mod math;
+pub mod ocr;
 pub mod prelude;
+pub mod scan;
 pub mod specific;
@@ -0,0 +1,202 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use super::hex::Axial;
+use super::{Direction8, Grid, Index};
+use crate::algo;
+
+/// 8-Neighborhood within the grid
+pub fn moore<T>(grid: &Grid<T>, idx: &Index) -> Vec<Index> {
+    idx.moore_neighbors(grid.size())
+}
+
+/// 4-Neighborhood within the grid
+pub fn von_neumann<T>(grid: &Grid<T>, idx: &Index) -> Vec<Index> {
+    idx.von_neumann_neighbors(grid.size())
+}
+
+/// First cell in each of the 8 directions, which is not transparent
+pub fn line_of_sight<T>(is_transparent: impl Fn(&T) -> bool) -> impl Fn(&Grid<T>, &Index) -> Vec<Index> {
+    move |grid, idx| {
+        Direction8::all()
+            .into_iter()
+            .filter_map(|dir| {
+                let step = |from: Index| from.advance8_check(dir, grid.size());
+                let mut current = step(*idx)?;
+                while is_transparent(grid.get(&current)) {
+                    current = step(current)?;
+                }
+                Some(current)
+            })
+            .collect()
+    }
+}
+
+/// 6 adjacent hex tiles
+pub fn hex(hex: &Axial) -> Vec<Axial> {
+    hex.neighbors().to_vec()
+}
+
+/// Synchronous update of all cells in a dense grid.
+/// The rule computes the next value of a cell from its current value and the values in its neighborhood.
+pub struct Automaton<T, N, R> {
+    neighborhood: N,
+    rule: R,
+    parallel: bool,
+    cell: PhantomData<T>,
+}
+
+impl<T, N, R> Automaton<T, N, R>
+where
+    T: Send + Sync,
+    N: Fn(&Grid<T>, &Index) -> Vec<Index> + Sync,
+    R: Fn(&T, &[&T]) -> T + Sync,
+{
+    pub fn new(neighborhood: N, rule: R) -> Self {
+        Automaton {
+            neighborhood,
+            rule,
+            parallel: false,
+            cell: PhantomData,
+        }
+    }
+
+    /// Updates the cells of each step in parallel, pays off for large grids or expensive neighborhoods
+    pub fn parallel(self) -> Self {
+        Automaton { parallel: true, ..self }
+    }
+
+    pub fn step(&self, grid: &Grid<T>) -> Grid<T> {
+        let width = grid.size().width;
+        let update = |(k, cell): (usize, &T)| {
+            let idx = Index::new(k % width, k / width);
+            let neighbors = (self.neighborhood)(grid, &idx)
+                .iter()
+                .map(|nb| grid.get(nb))
+                .collect_vec();
+            (self.rule)(cell, &neighbors)
+        };
+
+        let cells = if self.parallel {
+            grid.as_slice().par_iter().enumerate().map(update).collect()
+        } else {
+            grid.as_slice().iter().enumerate().map(update).collect()
+        };
+        Grid::from_cells(grid.size(), cells)
+    }
+
+    pub fn run(&self, grid: Grid<T>, steps: usize) -> Grid<T> {
+        (0..steps).fold(grid, |grid, _| self.step(&grid))
+    }
+
+    /// Steps until the grid doesn't change anymore, returns it with the number of changing steps
+    pub fn stabilize(&self, mut grid: Grid<T>) -> (Grid<T>, usize)
+    where
+        T: PartialEq,
+    {
+        let mut steps = 0;
+        loop {
+            let next = self.step(&grid);
+            if next == grid {
+                return (grid, steps);
+            }
+            grid = next;
+            steps += 1;
+        }
+    }
+
+    /// Grid after the given number of steps, skips ahead once the states repeat
+    pub fn final_state(&self, grid: Grid<T>, steps: usize) -> Grid<T>
+    where
+        T: Clone + Eq + Hash,
+    {
+        algo::find_final_state(grid, |grid| self.step(&grid), steps)
+    }
+}
+
+/// Synchronous update of an unbounded set of live cells.
+/// The rule decides from the current state and the number of live neighbors, whether a cell is alive afterwards.
+/// Only cells next to live cells are considered, hence the neighborhood needs to be symmetric
+/// and dead cells without live neighbors stay dead.
+pub struct SparseAutomaton<P, N, R> {
+    neighborhood: N,
+    rule: R,
+    parallel: bool,
+    cell: PhantomData<P>,
+}
+
+impl<P, N, R> SparseAutomaton<P, N, R>
+where
+    P: Clone + Eq + Hash + Send + Sync,
+    N: Fn(&P) -> Vec<P> + Sync,
+    R: Fn(bool, usize) -> bool + Sync,
+{
+    pub fn new(neighborhood: N, rule: R) -> Self {
+        SparseAutomaton {
+            neighborhood,
+            rule,
+            parallel: false,
+            cell: PhantomData,
+        }
+    }
+
+    /// Counts the live neighbors of each step in parallel
+    pub fn parallel(self) -> Self {
+        SparseAutomaton { parallel: true, ..self }
+    }
+
+    pub fn step(&self, live: &HashSet<P>) -> HashSet<P> {
+        let count = |mut counts: HashMap<P, usize>, cell: &P| {
+            for nb in (self.neighborhood)(cell) {
+                *counts.entry(nb).or_default() += 1;
+            }
+            counts
+        };
+
+        let counts = if self.parallel {
+            live.par_iter()
+                .fold(HashMap::new, count)
+                .reduce(HashMap::new, |mut all, counts| {
+                    for (cell, n) in counts {
+                        *all.entry(cell).or_default() += n;
+                    }
+                    all
+                })
+        } else {
+            live.iter().fold(HashMap::new(), count)
+        };
+
+        // Live cells without any live neighbor don't show up in the counts
+        let isolated = live
+            .iter()
+            .filter(|cell| !counts.contains_key(*cell) && (self.rule)(true, 0));
+        counts
+            .iter()
+            .filter(|(cell, n)| (self.rule)(live.contains(*cell), **n))
+            .map(|(cell, _)| cell)
+            .chain(isolated)
+            .cloned()
+            .collect()
+    }
+
+    pub fn run(&self, live: HashSet<P>, steps: usize) -> HashSet<P> {
+        (0..steps).fold(live, |live, _| self.step(&live))
+    }
+
+    /// Steps until the live cells don't change anymore, returns them with the number of changing steps
+    pub fn stabilize(&self, mut live: HashSet<P>) -> (HashSet<P>, usize) {
+        let mut steps = 0;
+        loop {
+            let next = self.step(&live);
+            if next == live {
+                return (live, steps);
+            }
+            live = next;
+            steps += 1;
+        }
+    }
+}
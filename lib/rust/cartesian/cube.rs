@@ -0,0 +1,268 @@
+use std::collections::{HashMap, VecDeque};
+
+use num::integer::Roots;
+
+use super::point::Point;
+use super::{Direction, Grid, Index, Size};
+
+type Vector = Point<3, i32>;
+
+/// Orientation of a face on the folded cube, as 3D vectors of the map's east and south and the outward normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Frame {
+    east: Vector,
+    south: Vector,
+    normal: Vector,
+}
+
+impl Frame {
+    /// 3D direction of a map direction on this face
+    fn vector(&self, dir: Direction) -> Vector {
+        match dir {
+            Direction::North => -self.south,
+            Direction::East => self.east,
+            Direction::South => self.south,
+            Direction::West => -self.east,
+            Direction::None => panic!("No vector for Direction::None"),
+        }
+    }
+
+    fn direction(&self, vector: Vector) -> Direction {
+        Direction::compass()
+            .into_iter()
+            .find(|dir| self.vector(*dir) == vector)
+            .unwrap_or_else(|| panic!("Vector {vector} is not parallel to the face"))
+    }
+
+    /// Frame of the face which is folded along the edge in the given direction.
+    /// Walking across that edge turns the walking direction into the inverse normal.
+    fn fold(&self, dir: Direction) -> Self {
+        let Frame { east, south, normal } = *self;
+        match dir {
+            Direction::North => Frame {
+                east,
+                south: normal,
+                normal: -south,
+            },
+            Direction::East => Frame {
+                east: -normal,
+                south,
+                normal: east,
+            },
+            Direction::South => Frame {
+                east,
+                south: -normal,
+                normal: south,
+            },
+            Direction::West => Frame {
+                east: normal,
+                south,
+                normal: -east,
+            },
+            Direction::None => panic!("Can't fold without direction"),
+        }
+    }
+}
+
+/// Map of the net of a cube, in which the 6 square faces are the non-blank blocks.
+/// Walking off a face continues on the face which is adjacent on the folded cube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CubeNet {
+    /// Side length of a face
+    pub face_size: usize,
+    /// Top left corner of each face on the map, in row-major order
+    pub faces: Vec<Index>,
+    /// Face and direction after walking off a face in some direction
+    seams: HashMap<(usize, Direction), (usize, Direction)>,
+}
+
+impl CubeNet {
+    pub fn new<T>(map: &Grid<T>, is_blank: impl Fn(&T) -> bool) -> Self {
+        let area = map.iter().filter(|cell| !is_blank(cell)).count();
+        let face_size = (area / 6).sqrt();
+        assert_eq!(6 * face_size * face_size, area, "Map is not the net of a cube");
+
+        let blocks = Size::new(map.size().width / face_size, map.size().height / face_size);
+        let faces = blocks
+            .indices()
+            .map(|block| Index::new(block.i * face_size, block.j * face_size))
+            .filter(|corner| !is_blank(map.get(corner)))
+            .collect::<Vec<_>>();
+        assert_eq!(faces.len(), 6, "Map is not the net of a cube");
+
+        // Fold the net starting from the first face, by walking through the faces adjacent on the map
+        let face_at = |corner: Index| faces.iter().position(|face| *face == corner);
+        let mut frames = vec![None; 6];
+        frames[0] = Some(Frame {
+            east: Point([1, 0, 0]),
+            south: Point([0, 1, 0]),
+            normal: Point([0, 0, 1]),
+        });
+        let mut queue = VecDeque::from([0]);
+        while let Some(face) = queue.pop_front() {
+            let frame = frames[face].unwrap();
+            for dir in Direction::compass() {
+                let block = Index::new(faces[face].i / face_size, faces[face].j / face_size);
+                let neighbor = block
+                    .advance_check(dir, blocks)
+                    .and_then(|next| face_at(Index::new(next.i * face_size, next.j * face_size)));
+                if let Some(neighbor) = neighbor.filter(|neighbor| frames[*neighbor].is_none()) {
+                    frames[neighbor] = Some(frame.fold(dir));
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        let frames = frames
+            .into_iter()
+            .map(|frame| frame.expect("Net of the cube is not connected"))
+            .collect::<Vec<_>>();
+
+        // Walking off a face leads onto the face whose normal points that way, moving away from the old normal
+        let mut seams = HashMap::new();
+        for (face, frame) in frames.iter().enumerate() {
+            for dir in Direction::compass() {
+                let target = frames
+                    .iter()
+                    .position(|other| other.normal == frame.vector(dir))
+                    .expect("Faces don't form a cube");
+                seams.insert((face, dir), (target, frames[target].direction(-frame.normal)));
+            }
+        }
+
+        CubeNet {
+            face_size,
+            faces,
+            seams,
+        }
+    }
+
+    /// Face the cell of the map belongs to
+    pub fn face_of(&self, idx: &Index) -> Option<usize> {
+        self.faces.iter().position(|corner| {
+            (corner.i..corner.i + self.face_size).contains(&idx.i)
+                && (corner.j..corner.j + self.face_size).contains(&idx.j)
+        })
+    }
+
+    /// Face and direction after walking off the face in the given direction
+    pub fn neighbor(&self, face: usize, dir: Direction) -> (usize, Direction) {
+        self.seams[&(face, dir)]
+    }
+
+    /// Cell on the map and direction after a single step on the surface of the cube
+    pub fn advance(&self, idx: &Index, dir: Direction) -> (Index, Direction) {
+        let face = self.face_of(idx).expect("Index is not on a face of the cube");
+        let corner = self.faces[face];
+        let local = Index::new(idx.i - corner.i, idx.j - corner.j);
+
+        let (face, dir, local) = match local.advance_check(dir, Size::square(self.face_size)) {
+            Some(next) => (face, dir, next),
+            None => {
+                let (target, target_dir) = self.neighbor(face, dir);
+                let offset = self.edge_offset(&local, dir);
+                (target, target_dir, self.entry(target_dir, offset))
+            }
+        };
+
+        let corner = self.faces[face];
+        (Index::new(corner.i + local.i, corner.j + local.j), dir)
+    }
+
+    /// Position of a cell on the edge in direction dir, counted from the left end when facing that way.
+    /// It stays the same when crossing a seam, as folding preserves the orientation.
+    fn edge_offset(&self, local: &Index, dir: Direction) -> usize {
+        let last = self.face_size - 1;
+        match dir {
+            Direction::North => local.i,
+            Direction::East => local.j,
+            Direction::South => last - local.i,
+            Direction::West => last - local.j,
+            Direction::None => panic!("Can't leave a face without direction"),
+        }
+    }
+
+    /// Cell reached when entering a face in direction dir, at the given edge offset
+    fn entry(&self, dir: Direction, offset: usize) -> Index {
+        let last = self.face_size - 1;
+        match dir {
+            Direction::North => Index::new(offset, last),
+            Direction::East => Index::new(0, offset),
+            Direction::South => Index::new(last - offset, 0),
+            Direction::West => Index::new(last, last - offset),
+            Direction::None => panic!("Can't enter a face without direction"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Net with faces of side 3, each block of the layout is either a face ('#') or blank
+    fn net(layout: &[&str]) -> CubeNet {
+        let width = layout.iter().map(|row| row.len()).max().unwrap();
+        let rows = layout
+            .iter()
+            .flat_map(|row| {
+                let cells = format!("{row:width$}")
+                    .chars()
+                    .flat_map(|block| [block == '#'; 3])
+                    .collect::<Vec<_>>();
+                [cells.clone(), cells.clone(), cells]
+            })
+            .collect();
+        CubeNet::new(&Grid::new(rows), |cell| !cell)
+    }
+
+    /// Every step off a face leads onto a distinct cell of another face, from which the reversed step leads back
+    fn assert_seams_round_trip(net: &CubeNet) {
+        let mut entered = HashSet::new();
+        for (face, corner) in net.faces.iter().enumerate() {
+            for idx in Size::square(net.face_size).indices() {
+                let start = Index::new(corner.i + idx.i, corner.j + idx.j);
+                for dir in Direction::compass() {
+                    let (next, next_dir) = net.advance(&start, dir);
+                    if net.face_of(&next) == Some(face) {
+                        continue;
+                    }
+
+                    assert!(entered.insert((next, next_dir)), "{next:?} is entered twice");
+                    assert_eq!(
+                        net.advance(&next, next_dir.inverse()),
+                        (start, dir.inverse()),
+                        "Stepping back from {next:?} doesn't return to {start:?}"
+                    );
+                }
+            }
+        }
+        assert_eq!(entered.len(), 6 * 4 * net.face_size);
+    }
+
+    #[test]
+    fn cross() {
+        let net = net(&[" #", "####", " #"]);
+        assert_eq!(net.neighbor(4, Direction::East), (1, Direction::East));
+        assert_eq!(net.neighbor(0, Direction::North), (4, Direction::South));
+        assert_seams_round_trip(&net);
+    }
+
+    #[test]
+    fn sample_shape() {
+        let net = net(&["  #", "###", "  ##"]);
+        assert_eq!(
+            net.advance(&Index::new(8, 3), Direction::East),
+            (Index::new(11, 6), Direction::South)
+        );
+        assert_seams_round_trip(&net);
+    }
+
+    #[test]
+    fn input_shape() {
+        let net = net(&[" ##", " #", "##", "#"]);
+        assert_eq!(net.neighbor(0, Direction::North), (5, Direction::East));
+        assert_eq!(net.neighbor(1, Direction::East), (4, Direction::West));
+        assert_seams_round_trip(&net);
+    }
+}
@@ -0,0 +1,261 @@
+use std::ops::Range;
+
+use num::PrimInt;
+
+use super::point::Point;
+
+/// Axis-aligned box of lattice cells in N dimensions, spanning [min, max) along each axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cuboid<const N: usize, T> {
+    pub min: Point<N, T>,
+    pub max: Point<N, T>,
+}
+
+impl<const N: usize, T: PrimInt> Cuboid<N, T> {
+    pub fn new(min: Point<N, T>, max: Point<N, T>) -> Self {
+        Cuboid { min, max }
+    }
+
+    /// Box containing both corners, as most inputs give them
+    pub fn from_inclusive(min: Point<N, T>, max: Point<N, T>) -> Self {
+        Cuboid::new(min, max + Point([T::one(); N]))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|k| self.min[k] >= self.max[k])
+    }
+
+    /// Number of contained cells
+    pub fn volume(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+        (0..N).fold(T::one(), |volume, k| volume * (self.max[k] - self.min[k]))
+    }
+
+    pub fn contains(&self, p: &Point<N, T>) -> bool {
+        (0..N).all(|k| self.min[k] <= p[k] && p[k] < self.max[k])
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let cut = Cuboid::new(self.min.component_max(&other.min), self.max.component_min(&other.max));
+        (!cut.is_empty()).then_some(cut)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Disjoint boxes covering all cells which are not in the other box (at most 2N)
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        // Slice off the slabs below and above the cut along each axis, the remainder is the cut itself
+        let mut rest = *self;
+        let mut pieces = Vec::new();
+        for k in 0..N {
+            if rest.min[k] < cut.min[k] {
+                let mut below = rest;
+                below.max[k] = cut.min[k];
+                pieces.push(below);
+                rest.min[k] = cut.min[k];
+            }
+            if cut.max[k] < rest.max[k] {
+                let mut above = rest;
+                above.min[k] = cut.max[k];
+                pieces.push(above);
+                rest.max[k] = cut.max[k];
+            }
+        }
+        pieces
+    }
+}
+
+/// Cells of the disjoint boxes which are not in the other box, again as disjoint boxes
+pub fn subtract_all<const N: usize, T: PrimInt>(disjoint: &[Cuboid<N, T>], other: &Cuboid<N, T>) -> Vec<Cuboid<N, T>> {
+    disjoint.iter().flat_map(|d| d.subtract(other)).collect()
+}
+
+/// Exact number of cells covered by at least one box
+pub fn union_volume<'a, const N: usize, T: PrimInt + 'a>(boxes: impl IntoIterator<Item = &'a Cuboid<N, T>>) -> T {
+    // Keep the covered cells as disjoint boxes, each new one replaces its overlap with the previous ones
+    let mut disjoint: Vec<Cuboid<N, T>> = Vec::new();
+    for b in boxes {
+        disjoint = subtract_all(&disjoint, b);
+        disjoint.push(*b);
+    }
+    disjoint.iter().fold(T::zero(), |volume, d| volume + d.volume())
+}
+
+/// Grid of compressed cells, bounded by all box boundaries along each axis.
+/// Each box is exactly covered by a block of compressed cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compression<const N: usize, T> {
+    /// Sorted boundaries along each axis
+    pub axes: [Vec<T>; N],
+}
+
+impl<const N: usize, T: PrimInt> Compression<N, T> {
+    pub fn new<'a>(boxes: impl IntoIterator<Item = &'a Cuboid<N, T>>) -> Self
+    where
+        T: 'a,
+    {
+        let mut axes: [Vec<T>; N] = std::array::from_fn(|_| Vec::new());
+        for b in boxes {
+            for (k, axis) in axes.iter_mut().enumerate() {
+                axis.extend([b.min[k], b.max[k]]);
+            }
+        }
+        for axis in axes.iter_mut() {
+            axis.sort();
+            axis.dedup();
+        }
+        Compression { axes }
+    }
+
+    /// Number of compressed cells along each axis
+    pub fn size(&self) -> [usize; N] {
+        std::array::from_fn(|k| self.axes[k].len().saturating_sub(1))
+    }
+
+    /// Total number of compressed cells
+    pub fn len(&self) -> usize {
+        self.size().iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Position of a compressed cell in a flat row-major vector, the first axis changes fastest
+    pub fn offset(&self, idx: &[usize; N]) -> usize {
+        let size = self.size();
+        (0..N).rev().fold(0, |offset, k| offset * size[k] + idx[k])
+    }
+
+    /// Actual box of a compressed cell
+    pub fn cell(&self, idx: &[usize; N]) -> Cuboid<N, T> {
+        Cuboid::new(
+            Point(std::array::from_fn(|k| self.axes[k][idx[k]])),
+            Point(std::array::from_fn(|k| self.axes[k][idx[k] + 1])),
+        )
+    }
+
+    /// Compressed cells along each axis covered by the box, whose boundaries need to be known
+    pub fn ranges(&self, b: &Cuboid<N, T>) -> [Range<usize>; N] {
+        let find = |k: usize, c: T| {
+            self.axes[k]
+                .binary_search(&c)
+                .unwrap_or_else(|_| panic!("Box boundary is not part of the compression"))
+        };
+        std::array::from_fn(|k| find(k, b.min[k])..find(k, b.max[k]))
+    }
+
+    /// Compressed cells covered by the box, in offset order
+    pub fn indices(&self, b: &Cuboid<N, T>) -> impl Iterator<Item = [usize; N]> {
+        Self::product(self.ranges(b))
+    }
+
+    /// All compressed cells in offset order
+    pub fn cells(&self) -> impl Iterator<Item = [usize; N]> {
+        Self::product(self.size().map(|len| 0..len))
+    }
+
+    fn product(ranges: [Range<usize>; N]) -> impl Iterator<Item = [usize; N]> {
+        // Odometer, in which the first axis changes fastest
+        let mut current = ranges.clone().map(|range| range.start);
+        let mut done = ranges.iter().any(|range| range.is_empty());
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let item = current;
+            done = true;
+            for (k, range) in ranges.iter().enumerate() {
+                current[k] += 1;
+                if current[k] < range.end {
+                    done = false;
+                    break;
+                }
+                current[k] = range.start;
+            }
+            Some(item)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cuboid<const N: usize>(min: [i32; N], max: [i32; N]) -> Cuboid<N, i32> {
+        Cuboid::from_inclusive(Point(min), Point(max))
+    }
+
+    #[test]
+    fn subtract() {
+        let a = cuboid([0, 0, 0], [9, 9, 9]);
+        let b = cuboid([3, -5, 4], [5, 20, 6]);
+        let pieces = a.subtract(&b);
+
+        assert!(pieces.len() <= 6);
+        assert!(pieces.iter().all(|piece| !piece.is_empty() && !piece.intersects(&b)));
+        assert!(pieces
+            .iter()
+            .enumerate()
+            .all(|(k, piece)| pieces[k + 1..].iter().all(|other| !piece.intersects(other))));
+        let overlap = a.intersection(&b).unwrap().volume();
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<i32>(), a.volume() - overlap);
+    }
+
+    #[test]
+    fn subtract_disjoint_and_covering() {
+        let a = cuboid([0, 0], [3, 3]);
+        assert_eq!(a.subtract(&cuboid([4, 0], [5, 3])), vec![a]);
+        assert!(a.subtract(&cuboid([-1, -1], [3, 3])).is_empty());
+    }
+
+    #[test]
+    fn union_volume_of_overlapping_boxes() {
+        let boxes = [
+            cuboid([0, 0], [3, 3]),
+            cuboid([2, 2], [5, 5]),
+            cuboid([1, 1], [1, 1]),
+            cuboid([10, 0], [10, 0]),
+        ];
+        assert_eq!(union_volume(&boxes), 16 + 16 - 4 + 1);
+        assert_eq!(union_volume::<2, i32>(&[]), 0);
+    }
+
+    #[test]
+    fn subtract_all_keeps_pieces_disjoint() {
+        let disjoint = vec![cuboid([0, 0], [1, 1]), cuboid([2, 0], [3, 1])];
+        let rest = subtract_all(&disjoint, &cuboid([1, 0], [2, 0]));
+        assert_eq!(rest.iter().map(Cuboid::volume).sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn compression() {
+        let boxes = [cuboid([0, 0], [3, 1]), cuboid([2, 1], [5, 4])];
+        let compression = Compression::new(&boxes);
+        assert_eq!(compression.axes, [vec![0, 2, 4, 6], vec![0, 1, 2, 5]]);
+        assert_eq!(compression.size(), [3, 3]);
+
+        assert_eq!(compression.ranges(&boxes[0]), [0..2, 0..2]);
+        assert_eq!(compression.ranges(&boxes[1]), [1..3, 1..3]);
+        assert_eq!(
+            compression.indices(&boxes[1]).collect::<Vec<_>>(),
+            [[1, 1], [2, 1], [1, 2], [2, 2]]
+        );
+
+        let volume = compression
+            .indices(&boxes[1])
+            .map(|idx| compression.cell(&idx).volume())
+            .sum::<i32>();
+        assert_eq!(volume, boxes[1].volume());
+        assert_eq!(compression.cells().count(), compression.len());
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::VecDeque;
+
+use super::{Direction8, Grid, Index};
+use crate::graph::PathFinding;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// 4 neighbors, excluding diagonals
+    VonNeumann,
+    /// 8 neighbors, including diagonals
+    Moore,
+}
+
+/// View of a grid as graph, in which each cell is connected to its neighbors.
+/// A move is only possible if `passable(from, to)` holds and costs `cost(from, to)`, both given the cell values.
+pub struct GridGraph<'a, T, P, C> {
+    pub grid: &'a Grid<T>,
+    pub(super) passable: P,
+    pub(super) cost: C,
+    pub(super) connectivity: Connectivity,
+}
+
+impl<'a, T> GridGraph<'a, T, fn(&T, &T) -> bool, fn(&T, &T) -> i64> {
+    /// All moves to one of the 4 neighbors are possible and cost 1
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        GridGraph {
+            grid,
+            passable: |_, _| true,
+            cost: |_, _| 1,
+            connectivity: Connectivity::VonNeumann,
+        }
+    }
+}
+
+impl<'a, T, P, C> GridGraph<'a, T, P, C>
+where
+    P: Fn(&T, &T) -> bool,
+    C: Fn(&T, &T) -> i64,
+{
+    pub fn passable<Q: Fn(&T, &T) -> bool>(self, passable: Q) -> GridGraph<'a, T, Q, C> {
+        GridGraph {
+            grid: self.grid,
+            passable,
+            cost: self.cost,
+            connectivity: self.connectivity,
+        }
+    }
+
+    pub fn cost<D: Fn(&T, &T) -> i64>(self, cost: D) -> GridGraph<'a, T, P, D> {
+        GridGraph {
+            grid: self.grid,
+            passable: self.passable,
+            cost,
+            connectivity: self.connectivity,
+        }
+    }
+
+    pub fn connectivity(self, connectivity: Connectivity) -> Self {
+        GridGraph { connectivity, ..self }
+    }
+
+    /// Cells reachable in a single move
+    pub fn neighbors(&self, idx: &Index) -> Vec<Index> {
+        let candidates = match self.connectivity {
+            Connectivity::VonNeumann => idx.von_neumann_neighbors(self.grid.size()),
+            Connectivity::Moore => idx.moore_neighbors(self.grid.size()),
+        };
+
+        let from = self.grid.get(idx);
+        candidates
+            .into_iter()
+            .filter(|nb| (self.passable)(from, self.grid.get(nb)))
+            .collect()
+    }
+
+    /// Number of moves from the start to each reachable cell, ignoring costs
+    pub fn distances(&self, start: &Index) -> Grid<Option<usize>> {
+        self.multi_source_distances([*start])
+    }
+
+    /// Number of moves from the closest start to each reachable cell, ignoring costs
+    pub fn multi_source_distances(&self, starts: impl IntoIterator<Item = Index>) -> Grid<Option<usize>> {
+        self.bfs(starts, None).map_elements(|visit| visit.map(|(dist, _)| dist))
+    }
+
+    /// Path with the fewest moves (including start and goal), ignoring costs
+    pub fn shortest_path(&self, start: &Index, goal: &Index) -> Option<Vec<Index>> {
+        let visits = self.bfs([*start], Some(goal));
+        visits.get(goal).as_ref()?;
+
+        let mut path = vec![*goal];
+        while let Some((_, Some(prev))) = visits.get(path.last().unwrap()) {
+            path.push(*prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Distance and predecessor of all visited cells, stops early once the goal is reached
+    fn bfs(
+        &self,
+        starts: impl IntoIterator<Item = Index>,
+        goal: Option<&Index>,
+    ) -> Grid<Option<(usize, Option<Index>)>> {
+        let mut visits = Grid::empty(self.grid.size(), None);
+        breadth_first(&mut visits, starts, |idx| self.neighbors(idx), goal, None);
+        visits
+    }
+}
+
+/// Record of the nodes visited by `breadth_first`
+pub(super) trait Visits<N> {
+    fn is_visited(&self, node: &N) -> bool;
+    fn visit(&mut self, node: N, dist: usize, prev: Option<N>);
+}
+
+impl Visits<Index> for Grid<Option<(usize, Option<Index>)>> {
+    fn is_visited(&self, idx: &Index) -> bool {
+        self.get(idx).is_some()
+    }
+
+    fn visit(&mut self, idx: Index, dist: usize, prev: Option<Index>) {
+        self.set(&idx, Some((dist, prev)));
+    }
+}
+
+/// Breadth-first search from the starts, which stops once the goal is reached
+/// and doesn't move on from nodes at the maximum distance
+pub(super) fn breadth_first<N: Copy + PartialEq>(
+    visits: &mut impl Visits<N>,
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl Fn(&N) -> Vec<N>,
+    goal: Option<&N>,
+    max_dist: Option<usize>,
+) {
+    let mut queue = VecDeque::new();
+    for start in starts {
+        visits.visit(start, 0, None);
+        queue.push_back((start, 0));
+    }
+
+    while let Some((node, dist)) = queue.pop_front() {
+        if goal == Some(&node) {
+            break;
+        }
+        if max_dist == Some(dist) {
+            continue;
+        }
+
+        for nb in neighbors(&node) {
+            if !visits.is_visited(&nb) {
+                visits.visit(nb, dist + 1, Some(node));
+                queue.push_back((nb, dist + 1));
+            }
+        }
+    }
+}
+
+impl<T, P, C> PathFinding<Index> for GridGraph<'_, T, P, C>
+where
+    P: Fn(&T, &T) -> bool,
+    C: Fn(&T, &T) -> i64,
+{
+    fn transitions(&mut self, current: &Index) -> Vec<(Index, i64)> {
+        let from = self.grid.get(current);
+        self.neighbors(current)
+            .into_iter()
+            .map(|nb| (nb, (self.cost)(from, self.grid.get(&nb))))
+            .collect()
+    }
+}
+
+/// Directions of the single steps along a path of adjacent cells, including diagonal steps
+pub fn route(path: &[Index]) -> Vec<Direction8> {
+    path.windows(2)
+        .map(|step| {
+            let offset = (
+                step[1].i as i128 - step[0].i as i128,
+                step[1].j as i128 - step[0].j as i128,
+            );
+            Direction8::from_offset(offset)
+                .filter(|dir| dir.offset() == offset)
+                .unwrap_or_else(|| panic!("{:?} and {:?} are not adjacent", step[0], step[1]))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moore_route_around_wall() {
+        let grid = Grid::new(vec![
+            ".#.".chars().collect(),
+            ".#.".chars().collect(),
+            "...".chars().collect(),
+        ]);
+        let graph = GridGraph::new(&grid)
+            .passable(|_, to| *to != '#')
+            .connectivity(Connectivity::Moore);
+
+        let path = graph.shortest_path(&Index::new(0, 0), &Index::new(2, 0)).unwrap();
+        assert_eq!(
+            route(&path),
+            [
+                Direction8::South,
+                Direction8::SouthEast,
+                Direction8::NorthEast,
+                Direction8::North
+            ]
+        );
+    }
+}
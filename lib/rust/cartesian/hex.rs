@@ -0,0 +1,327 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Add, AddAssign, Mul, Sub},
+};
+
+use super::HexIndex;
+
+// Axial and cube coordinates as described in https://www.redblobgames.com/grids/hexagons/
+
+/// Axial unit vectors, in counterclockwise order
+const DIRECTIONS: [Axial; 6] = [
+    Axial { q: 1, r: 0 },
+    Axial { q: 1, r: -1 },
+    Axial { q: 0, r: -1 },
+    Axial { q: -1, r: 0 },
+    Axial { q: -1, r: 1 },
+    Axial { q: 0, r: 1 },
+];
+
+/// Whether the hexagons have a flat side or a corner at the top
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexOrientation {
+    /// Neighbors se, ne, n, nw, sw, s
+    FlatTop,
+    /// Neighbors e, ne, nw, w, sw, se
+    PointyTop,
+}
+
+impl HexOrientation {
+    /// Names of the directions, in the same order as the axial unit vectors
+    pub fn names(&self) -> [&'static str; 6] {
+        match self {
+            HexOrientation::FlatTop => ["se", "ne", "n", "nw", "sw", "s"],
+            HexOrientation::PointyTop => ["e", "ne", "nw", "w", "sw", "se"],
+        }
+    }
+
+    /// Unit vector for a direction name (case insensitive)
+    pub fn direction(&self, name: &str) -> Option<Axial> {
+        let name = name.to_ascii_lowercase();
+        self.names().iter().position(|n| *n == name).map(|k| DIRECTIONS[k])
+    }
+
+    /// Steps of a path given as direction names, which may be separated by commas or whitespace or not at all
+    pub fn parse_path(&self, path: &str) -> Vec<Axial> {
+        let mut steps = Vec::new();
+        let mut rest = path.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        while !rest.is_empty() {
+            // Two letter names first, "ne" is never "n" followed by "e"
+            let (step, len) = [2, 1]
+                .into_iter()
+                .find_map(|len| self.direction(rest.get(..len)?).map(|step| (step, len)))
+                .unwrap_or_else(|| panic!("Unknown hex direction at '{rest}'"));
+            steps.push(step);
+            rest = rest[len..].trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        }
+        steps
+    }
+}
+
+/// Hex coordinates with q + r + s = 0, where s is implicit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Axial {
+    pub q: i128,
+    pub r: i128,
+}
+
+impl Axial {
+    pub fn new(q: i128, r: i128) -> Self {
+        Axial { q, r }
+    }
+
+    pub fn zero() -> Self {
+        Axial { q: 0, r: 0 }
+    }
+
+    pub fn s(&self) -> i128 {
+        -self.q - self.r
+    }
+
+    pub fn to_cube(&self) -> Cube {
+        (*self).into()
+    }
+
+    /// Unit vectors in counterclockwise order, see `HexOrientation::names` for their names
+    pub fn directions() -> [Axial; 6] {
+        DIRECTIONS
+    }
+
+    /// 6 adjacent hexes, in the order of the directions
+    pub fn neighbors(&self) -> [Axial; 6] {
+        DIRECTIONS.map(|dir| *self + dir)
+    }
+
+    /// Number of steps between both hexes
+    pub fn dist(&self, other: &Axial) -> u128 {
+        let diff = *self - *other;
+        (diff.q.unsigned_abs() + diff.r.unsigned_abs() + diff.s().unsigned_abs()) / 2
+    }
+
+    /// Rotation by 60° clockwise around the origin
+    pub fn rotate_right(&self) -> Self {
+        Axial::new(-self.r, -self.s())
+    }
+
+    /// Rotation by 60° counterclockwise around the origin
+    pub fn rotate_left(&self) -> Self {
+        Axial::new(-self.s(), -self.q)
+    }
+
+    /// All hexes in the given distance, walking counterclockwise around the center
+    pub fn ring(&self, radius: u128) -> Vec<Axial> {
+        if radius == 0 {
+            return vec![*self];
+        }
+
+        let mut current = *self + DIRECTIONS[4] * radius as i128;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for dir in DIRECTIONS {
+            for _ in 0..radius {
+                ring.push(current);
+                current += dir;
+            }
+        }
+        ring
+    }
+
+    /// All hexes up to the given distance, ring by ring starting with the center
+    pub fn spiral(&self, radius: u128) -> Vec<Axial> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// Hexes on the straight line to the other one, including both ends
+    pub fn line_to(&self, other: &Axial) -> Vec<Axial> {
+        let steps = self.dist(other);
+        // Nudge the line, so that it doesn't run exactly along the edges of hexes
+        let (from, to) = (self.to_cube(), other.to_cube());
+        let from = [from.q as f64 + 1e-6, from.r as f64 + 2e-6, from.s as f64 - 3e-6];
+        let to = [to.q as f64 + 1e-6, to.r as f64 + 2e-6, to.s as f64 - 3e-6];
+
+        (0..=steps)
+            .map(|k| {
+                let t = if steps == 0 { 0.0 } else { k as f64 / steps as f64 };
+                let lerp: [f64; 3] = std::array::from_fn(|c| from[c] + (to[c] - from[c]) * t);
+                Cube::round(lerp).into()
+            })
+            .collect()
+    }
+}
+
+impl Add for Axial {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Axial::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Axial {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Axial {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Axial::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Mul<i128> for Axial {
+    type Output = Self;
+
+    fn mul(self, rhs: i128) -> Self::Output {
+        Axial::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+/// Double-height coordinates are flat top with the same columns
+impl From<HexIndex> for Axial {
+    fn from(idx: HexIndex) -> Self {
+        Axial::new(idx.x, (idx.y - idx.x) / 2)
+    }
+}
+
+impl From<Axial> for HexIndex {
+    fn from(axial: Axial) -> Self {
+        HexIndex {
+            x: axial.q,
+            y: 2 * axial.r + axial.q,
+        }
+    }
+}
+
+/// Hex coordinates with explicit s, always satisfying q + r + s = 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cube {
+    pub q: i128,
+    pub r: i128,
+    pub s: i128,
+}
+
+impl Cube {
+    pub fn new(q: i128, r: i128, s: i128) -> Self {
+        assert_eq!(q + r + s, 0, "Cube coordinates need to sum up to 0");
+        Cube { q, r, s }
+    }
+
+    /// Closest hex to fractional coordinates
+    fn round([q, r, s]: [f64; 3]) -> Self {
+        let (mut rq, mut rr, mut rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+
+        // Fix the component with the largest rounding error
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        } else {
+            rs = -rq - rr;
+        }
+        Cube::new(rq as i128, rr as i128, rs as i128)
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(axial: Axial) -> Self {
+        Cube::new(axial.q, axial.r, axial.s())
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(cube: Cube) -> Self {
+        Axial::new(cube.q, cube.r)
+    }
+}
+
+/// Unbounded hex grid, storing only cells which have been set explicitly.
+/// All other cells have the default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexGrid<T> {
+    cells: HashMap<Axial, T>,
+    default: T,
+}
+
+impl<T> HexGrid<T> {
+    pub fn new(default: T) -> Self {
+        HexGrid {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Value of the hex, default if it hasn't been set
+    pub fn get(&self, hex: &Axial) -> &T {
+        self.cells.get(hex).unwrap_or(&self.default)
+    }
+
+    /// Value of the hex, only if it has been set
+    pub fn get_checked(&self, hex: &Axial) -> Option<&T> {
+        self.cells.get(hex)
+    }
+
+    /// Mutable value of the hex, sets it to the default first if necessary
+    pub fn get_mut(&mut self, hex: &Axial) -> &mut T
+    where
+        T: Clone,
+    {
+        self.cells.entry(*hex).or_insert_with(|| self.default.clone())
+    }
+
+    pub fn set(&mut self, hex: &Axial, value: T) {
+        self.cells.insert(*hex, value);
+    }
+
+    /// Resets the hex to the default, returns the previous value if it was set
+    pub fn remove(&mut self, hex: &Axial) -> Option<T> {
+        self.cells.remove(hex)
+    }
+
+    pub fn contains(&self, hex: &Axial) -> bool {
+        self.cells.contains_key(hex)
+    }
+
+    /// Number of set hexes
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// All set hexes in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&Axial, &T)> {
+        self.cells.iter()
+    }
+
+    /// Values of the 6 adjacent hexes, including unset ones
+    pub fn neighbors(&self, hex: &Axial) -> Vec<(Axial, &T)> {
+        hex.neighbors().into_iter().map(|nb| (nb, self.get(&nb))).collect()
+    }
+
+    /// Set hexes satisfying the predicate, e.g. the live cells for a `SparseAutomaton`
+    pub fn hexes_where<F: Fn(&T) -> bool>(&self, predicate: F) -> HashSet<Axial> {
+        self.cells
+            .iter()
+            .filter(|(_, value)| predicate(value))
+            .map(|(hex, _)| *hex)
+            .collect()
+    }
+}
+
+impl<T: Default> FromIterator<(Axial, T)> for HexGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Axial, T)>>(iter: I) -> Self {
+        HexGrid {
+            cells: iter.into_iter().collect(),
+            default: T::default(),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use itertools::Itertools;
+
+use super::{Grid, Size};
+
+/// Color as red, green and blue channel
+pub type Rgb = [u8; 3];
+
+impl<T> Grid<T> {
+    /// Black and white image (plain PBM), with black for all cells for which `is_black` holds
+    pub fn write_pbm<W: Write>(&self, out: &mut W, is_black: impl Fn(&T) -> bool) -> io::Result<()> {
+        writeln!(out, "P1\n{} {}", self.size().width, self.size().height)?;
+        for row in self.rows() {
+            // Lines of plain PBM files should not be longer than 70 characters
+            for chunk in row.chunks(35) {
+                writeln!(
+                    out,
+                    "{}",
+                    chunk
+                        .iter()
+                        .map(|cell| if is_black(cell) { '1' } else { '0' })
+                        .join(" ")
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Color image (binary PPM), with one pixel per cell
+    pub fn write_ppm<W: Write>(&self, out: &mut W, color: impl Fn(&T) -> Rgb) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.size().width, self.size().height)?;
+        out.write_all(&self.iter().flat_map(color).collect_vec())
+    }
+
+    /// Vector image, in which each cell is a square with the given side length
+    pub fn write_svg<W: Write>(&self, out: &mut W, cell_size: usize, color: impl Fn(&T) -> Rgb) -> io::Result<()> {
+        svg_header(out, self.size(), cell_size)?;
+        writeln!(out, "{}", svg_cells(&self.map_elements(color), cell_size))?;
+        writeln!(out, "</svg>")
+    }
+}
+
+/// Collects frames of a changing grid, to be written as animation or image sequence
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    frames: Vec<Grid<Rgb>>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Recorder { frames: Vec::new() }
+    }
+
+    /// Adds the grid as next frame, all frames need to have the same size
+    pub fn record<T>(&mut self, grid: &Grid<T>, color: impl Fn(&T) -> Rgb) {
+        if let Some(first) = self.frames.first() {
+            assert_eq!(first.size(), grid.size(), "All frames need to have the same size");
+        }
+        self.frames.push(grid.map_elements(color));
+    }
+
+    pub fn frames(&self) -> &[Grid<Rgb>] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Animated vector image, which shows each frame for the given number of seconds and then loops
+    pub fn write_svg<W: Write>(&self, out: &mut W, cell_size: usize, frame_secs: f64) -> io::Result<()> {
+        let Some(first) = self.frames.first() else {
+            return Ok(());
+        };
+
+        svg_header(out, first.size(), cell_size)?;
+        let count = self.frames.len();
+        let duration = frame_secs * count as f64;
+        for (k, frame) in self.frames.iter().enumerate() {
+            // Each frame is only visible during its own share of the loop
+            let mut key_times = vec![0.0];
+            let mut values = vec![if k == 0 { "visible" } else { "hidden" }];
+            if k > 0 {
+                key_times.push(k as f64 / count as f64);
+                values.push("visible");
+            }
+            if k + 1 < count {
+                key_times.push((k + 1) as f64 / count as f64);
+                values.push("hidden");
+            }
+
+            writeln!(
+                out,
+                "<g visibility=\"{}\"><animate attributeName=\"visibility\" calcMode=\"discrete\" dur=\"{duration}s\" \
+                 repeatCount=\"indefinite\" keyTimes=\"{}\" values=\"{}\"/>",
+                values[0],
+                key_times.iter().join(";"),
+                values.join(";"),
+            )?;
+            writeln!(out, "{}", svg_cells(frame, cell_size))?;
+            writeln!(out, "</g>")?;
+        }
+        writeln!(out, "</svg>")
+    }
+
+    /// Writes each frame as PPM image into the directory, named by its number (frame_0000.ppm, ...)
+    pub fn write_ppm_sequence(&self, dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        for (k, frame) in self.frames.iter().enumerate() {
+            let mut out = BufWriter::new(File::create(dir.join(format!("frame_{k:04}.ppm")))?);
+            frame.write_ppm(&mut out, |rgb| *rgb)?;
+            out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+fn svg_header<W: Write>(out: &mut W, size: Size, cell_size: usize) -> io::Result<()> {
+    let (width, height) = (size.width * cell_size, size.height * cell_size);
+    writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">"
+    )
+}
+
+/// Rectangles for all cells, neighboring cells of the same color within a row are merged
+fn svg_cells(colors: &Grid<Rgb>, cell_size: usize) -> String {
+    let mut rects = Vec::new();
+    for (j, row) in colors.rows().enumerate() {
+        let mut i = 0;
+        for (rgb, run) in &row.iter().group_by(|rgb| **rgb) {
+            let len = run.count();
+            rects.push(format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{cell_size}\" fill=\"#{:02x}{:02x}{:02x}\"/>",
+                i * cell_size,
+                j * cell_size,
+                len * cell_size,
+                rgb[0],
+                rgb[1],
+                rgb[2]
+            ));
+            i += len;
+        }
+    }
+    rects.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pbm() {
+        let grid = Grid::new(vec![vec![true, false, false], vec![false, true, true]]);
+        let mut out = Vec::new();
+        grid.write_pbm(&mut out, |black| *black).unwrap();
+        assert_eq!(out, b"P1\n3 2\n1 0 0\n0 1 1\n");
+    }
+
+    #[test]
+    fn pbm_long_rows() {
+        let grid = Grid::empty(Size::new(80, 2), true);
+        let mut out = Vec::new();
+        grid.write_pbm(&mut out, |black| *black).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines = text.lines().collect_vec();
+        assert_eq!(lines.len(), 2 + 2 * 3);
+        assert!(lines.iter().all(|line| line.len() <= 70));
+        assert_eq!(
+            lines[2..].iter().map(|line| line.split(' ').count()).collect_vec(),
+            [35, 35, 10, 35, 35, 10]
+        );
+    }
+
+    #[test]
+    fn ppm() {
+        let grid = Grid::new(vec![vec![0u8, 1], vec![2, 0]]);
+        let mut out = Vec::new();
+        grid.write_ppm(&mut out, |v| [*v, 10 * *v, 255]).unwrap();
+
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([0, 0, 255, 1, 10, 255, 2, 20, 255, 0, 0, 255]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn animated_svg() {
+        let mut recorder = Recorder::new();
+        for k in 0..3 {
+            recorder.record(&Grid::new(vec![vec![k]]), |v| [*v * 100, 0, 0]);
+        }
+        let mut out = Vec::new();
+        recorder.write_svg(&mut out, 2, 0.5).unwrap();
+
+        let svg = String::from_utf8(out).unwrap();
+        let animations = svg.lines().filter(|line| line.starts_with("<g ")).collect_vec();
+        let expected = [
+            ("visible", "0;0.3333333333333333", "visible;hidden"),
+            (
+                "hidden",
+                "0;0.3333333333333333;0.6666666666666666",
+                "hidden;visible;hidden",
+            ),
+            ("hidden", "0;0.6666666666666666", "hidden;visible"),
+        ];
+        assert_eq!(animations.len(), expected.len());
+        for (line, (visibility, key_times, values)) in animations.iter().zip(expected) {
+            assert!(line.starts_with(&format!("<g visibility=\"{visibility}\">")));
+            assert!(line.contains("dur=\"1.5s\""));
+            assert!(line.contains(&format!("keyTimes=\"{key_times}\" values=\"{values}\"")));
+        }
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"2\" fill=\"#c80000\"/>"));
+    }
+}
@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use num::Integer;
+
+use super::Position;
+
+/// Smallest lattice step pointing from one position towards the other, (0, 0) if both are equal
+pub fn reduced_direction(from: &Position, to: &Position) -> (i128, i128) {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let gcd = dx.gcd(&dy);
+    if gcd == 0 {
+        (0, 0)
+    } else {
+        (dx / gcd, dy / gcd)
+    }
+}
+
+/// All lattice points lying exactly on the segment, including both ends.
+/// For horizontal, vertical and diagonal lines these are all cells covered by the line.
+pub fn lattice_line(from: &Position, to: &Position) -> Vec<Position> {
+    let (dx, dy) = reduced_direction(from, to);
+    let steps = if dx != 0 {
+        (to.x - from.x) / dx
+    } else {
+        (to.y - from.y).checked_div(dy).unwrap_or(0)
+    };
+    (0..=steps).map(|k| *from + (k * dx, k * dy)).collect()
+}
+
+/// 8-connected raster approximation of the segment (Bresenham), including both ends
+pub fn bresenham(from: &Position, to: &Position) -> Vec<Position> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (sx, sy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+
+    let mut current = *from;
+    let mut err = dx + dy;
+    let mut line = vec![current];
+    while current != *to {
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            current.x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            current.y += sy;
+        }
+        line.push(current);
+    }
+    line
+}
+
+/// Orders directions clockwise on screen (y pointing south), starting with north
+pub fn clockwise_cmp(a: &(i128, i128), b: &(i128, i128)) -> Ordering {
+    // North inclusive up to south exclusive is the first half turn
+    let half = |(dx, dy): &(i128, i128)| !(*dx > 0 || (*dx == 0 && *dy < 0));
+    half(a).cmp(&half(b)).then_with(|| 0.cmp(&(a.0 * b.1 - a.1 * b.0)))
+}
+
+/// Points grouped by the ray from the center they lie on, rays in clockwise order starting north.
+/// Each ray is ordered by distance to the center, which itself is skipped.
+pub fn rays<'a>(center: &Position, points: impl IntoIterator<Item = &'a Position>) -> Vec<Vec<Position>> {
+    points
+        .into_iter()
+        .filter(|p| *p != center)
+        .into_group_map_by(|p| reduced_direction(center, p))
+        .into_iter()
+        .sorted_by(|(a, _), (b, _)| clockwise_cmp(a, b))
+        .map(|(_, ray)| ray.into_iter().copied().sorted_by_key(|p| p.dist(center)).collect())
+        .collect()
+}
+
+/// Closest point on each ray from the center, i.e. all points visible from there
+pub fn visible<'a>(center: &Position, points: impl IntoIterator<Item = &'a Position>) -> Vec<Position> {
+    rays(center, points).into_iter().map(|ray| ray[0]).collect()
+}
+
+/// Order in which a clockwise rotating beam starting north hits the points, if it only hits one per ray and turn
+pub fn sweep_order<'a>(center: &Position, points: impl IntoIterator<Item = &'a Position>) -> Vec<Position> {
+    let rays = rays(center, points);
+    let turns = rays.iter().map(Vec::len).max().unwrap_or(0);
+    (0..turns)
+        .flat_map(|turn| rays.iter().filter_map(move |ray| ray.get(turn).copied()))
+        .collect()
+}
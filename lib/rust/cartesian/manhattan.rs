@@ -0,0 +1,312 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use itertools::{iproduct, Itertools};
+use num::{PrimInt, Signed};
+
+use super::cuboid::{Compression, Cuboid};
+use super::point::Point;
+use crate::interval::Interval;
+
+/// All points within the Manhattan distance radius around the center (a diamond in 2D, an octahedron in 3D)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ball<const N: usize, T> {
+    pub center: Point<N, T>,
+    pub radius: T,
+}
+
+impl<const N: usize, T: PrimInt + Signed> Ball<N, T> {
+    pub fn new(center: Point<N, T>, radius: T) -> Self {
+        Ball { center, radius }
+    }
+
+    pub fn contains(&self, p: &Point<N, T>) -> bool {
+        self.center.manhattan(p) <= self.radius
+    }
+
+    /// Both balls share at least one point (not necessarily a lattice point)
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.center.manhattan(&other.center) <= self.radius + other.radius
+    }
+
+    /// The ball cut by the hyperplane where the axis has the given value, as ball within that hyperplane
+    pub fn cross_section(&self, axis: usize, value: T) -> Option<Self> {
+        let radius = self.radius - (self.center[axis] - value).abs();
+        let mut center = self.center;
+        center[axis] = value;
+        (radius >= T::zero()).then_some(Ball { center, radius })
+    }
+
+    /// Covered values along the axis on the line through the point, e.g. the covered part of a row in 2D
+    pub fn line_interval(&self, axis: usize, through: &Point<N, T>) -> Interval<T> {
+        let mut on_axis = *through;
+        on_axis[axis] = self.center[axis];
+        let remaining = self.radius - self.center.manhattan(&on_axis);
+        if remaining < T::zero() {
+            return Interval::empty();
+        }
+        Interval::closed(self.center[axis] - remaining, self.center[axis] + remaining)
+    }
+
+    /// Shortest distance between the center and any cell of the box
+    pub fn dist_to_box(&self, b: &Cuboid<N, T>) -> T {
+        box_dist(&self.center, b)
+    }
+
+    pub fn intersects_box(&self, b: &Cuboid<N, T>) -> bool {
+        !b.is_empty() && self.dist_to_box(b) <= self.radius
+    }
+}
+
+impl<T: PrimInt + Signed> Ball<2, T> {
+    /// The diamond becomes an axis-aligned square in rotated coordinates, see `rotate`
+    pub fn rotated(&self) -> Cuboid<2, T> {
+        let center = rotate(&self.center);
+        let radius = Point([self.radius; 2]);
+        Cuboid::from_inclusive(center - radius, center + radius)
+    }
+}
+
+/// Manhattan distance between the point and the closest cell of the box
+fn box_dist<const N: usize, T: PrimInt + Signed>(p: &Point<N, T>, b: &Cuboid<N, T>) -> T {
+    (0..N).fold(T::zero(), |dist, k| {
+        let last = b.max[k] - T::one();
+        dist + (b.min[k] - p[k]).max(p[k] - last).max(T::zero())
+    })
+}
+
+/// Rotation by 45° with scaling, (x, y) -> (u, v) = (x + y, x - y), which turns Manhattan into Chebyshev distance
+pub fn rotate<T: PrimInt + Signed>(p: &Point<2, T>) -> Point<2, T> {
+    Point([p[0] + p[1], p[0] - p[1]])
+}
+
+/// Inverse of `rotate`, only lattice points where u and v have the same parity map back to lattice points
+pub fn unrotate<T: PrimInt + Signed>(p: &Point<2, T>) -> Option<Point<2, T>> {
+    let two = T::one() + T::one();
+    let (sum, diff) = (p[0] + p[1], p[0] - p[1]);
+    (sum % two == T::zero()).then(|| Point([sum / two, diff / two]))
+}
+
+/// The only lattice point within the bounds (inclusive) which none of the balls cover, if it is unique.
+/// Its neighbors are then covered or out of bounds, which puts it within one step of a corner of its free cell
+/// in rotated coordinates, or on a corner of the bounds.
+pub fn uncovered_point<T: PrimInt + Signed>(
+    balls: &[Ball<2, T>],
+    min: &Point<2, T>,
+    max: &Point<2, T>,
+) -> Option<Point<2, T>> {
+    // Areas covered by balls are squares in rotated coordinates, compress those to find the free areas
+    let squares = balls.iter().map(Ball::rotated).collect_vec();
+    let compression = Compression::new(&squares);
+    let mut covered = vec![false; compression.len()];
+    for square in &squares {
+        for idx in compression.indices(square) {
+            covered[compression.offset(&idx)] = true;
+        }
+    }
+
+    let bound_corners = [*min, Point([max[0], min[1]]), Point([min[0], max[1]]), *max];
+    compression
+        .cells()
+        .filter(|idx| !covered[compression.offset(idx)])
+        .flat_map(|idx| corner_candidates(&compression.cell(&idx)))
+        .chain(bound_corners)
+        .find(|p| p.within(min, max) && balls.iter().all(|ball| !ball.contains(p)))
+}
+
+/// Lattice points of a rotated cell at most one step away from one of its corners
+fn corner_candidates<T: PrimInt + Signed>(cell: &Cuboid<2, T>) -> Vec<Point<2, T>> {
+    let near = |k: usize| {
+        let (first, last) = (cell.min[k], cell.max[k] - T::one());
+        [first, first + T::one(), last - T::one(), last]
+    };
+    iproduct!(near(0), near(1))
+        .map(|(u, v)| Point([u, v]))
+        .filter(|p| cell.contains(p))
+        .filter_map(|p| unrotate(&p))
+        .collect()
+}
+
+/// Point covered by the most balls, the closest one to the origin among those.
+/// Returns it together with the number of covering balls.
+pub fn most_covered<const N: usize, T: PrimInt + Signed>(balls: &[Ball<N, T>]) -> Option<(Point<N, T>, usize)> {
+    let origin = Point::zero();
+    let extent = balls
+        .iter()
+        .flat_map(|ball| (0..N).map(move |k| ball.center[k].abs() + ball.radius))
+        .max()?;
+
+    // Branch and bound over an octree: the number of balls touching a box bounds the coverage of its points
+    let mut size = T::one();
+    while size <= extent {
+        size = size + size;
+    }
+    let root = Cuboid::new(Point([-size; N]), Point([size; N]));
+    let covering = |b: &Cuboid<N, T>| balls.iter().filter(|ball| ball.intersects_box(b)).count();
+
+    let mut heap = BinaryHeap::from([(covering(&root), Reverse(box_dist(&origin, &root)), size + size, root)]);
+    while let Some((count, _, side, b)) = heap.pop() {
+        if side == T::one() {
+            return Some((b.min, count));
+        }
+
+        let half = side / (T::one() + T::one());
+        for octant in 0..1 << N {
+            let min = Point(std::array::from_fn(|k| {
+                if octant >> k & 1 == 1 {
+                    b.min[k] + half
+                } else {
+                    b.min[k]
+                }
+            }));
+            let child = Cuboid::new(min, min + Point([half; N]));
+            heap.push((covering(&child), Reverse(box_dist(&origin, &child)), half, child));
+        }
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::generator::SeededRng;
+
+    fn ball<const N: usize>(center: [i64; N], radius: i64) -> Ball<N, i64> {
+        Ball::new(Point(center), radius)
+    }
+
+    #[test]
+    fn rotation() {
+        for p in [Point([0, 0]), Point([3, -7]), Point([-4, -4]), Point([5, 2])] {
+            assert_eq!(unrotate(&rotate(&p)), Some(p));
+        }
+        assert_eq!(unrotate(&Point([1, 0])), None);
+        assert_eq!(unrotate(&Point([-3, 2])), None);
+    }
+
+    #[test]
+    fn distance_to_box() {
+        let b = Cuboid::from_inclusive(Point([0, 0, 0]), Point([2, 2, 2]));
+        assert_eq!(box_dist(&Point([1, 1, 1]), &b), 0);
+        assert_eq!(box_dist(&Point([2, 2, 2]), &b), 0);
+        assert_eq!(box_dist(&Point([3, 1, 1]), &b), 1);
+        assert_eq!(box_dist(&Point([-1, 4, 5]), &b), 1 + 2 + 3);
+    }
+
+    #[test]
+    fn most_covered_nanobots() {
+        let nanobots = [
+            ball([10, 12, 12], 2),
+            ball([12, 14, 12], 2),
+            ball([16, 12, 12], 4),
+            ball([14, 14, 14], 6),
+            ball([50, 50, 50], 200),
+            ball([10, 10, 10], 5),
+        ];
+        assert_eq!(most_covered(&nanobots), Some((Point([12, 12, 12]), 5)));
+    }
+
+    #[test]
+    fn most_covered_brute_force() {
+        let mut rng = SeededRng::new(23);
+        for _ in 0..50 {
+            let balls = (0..rng.range(1..8))
+                .map(|_| ball([rng.range(-6..7), rng.range(-6..7), rng.range(-6..7)], rng.range(0..5)))
+                .collect_vec();
+            let (best, count) = most_covered(&balls).unwrap();
+
+            let coverage = |p: &Point<3, i64>| balls.iter().filter(|ball| ball.contains(p)).count();
+            let (expected_count, expected_dist) = iproduct!(-11..12, -11..12, -11..12)
+                .map(|(x, y, z)| Point([x, y, z]))
+                .map(|p| (coverage(&p), Reverse(p.manhattan(&Point::zero()))))
+                .max()
+                .unwrap();
+            assert_eq!((coverage(&best), count), (expected_count, expected_count));
+            assert_eq!(best.manhattan(&Point::zero()), expected_dist.0);
+        }
+    }
+
+    #[test]
+    fn uncovered_point_inside() {
+        // Sensors of the 2022 day 15 sample, with the radius given by the closest beacon
+        let sensors = [
+            ([2, 18], [-2, 15]),
+            ([9, 16], [10, 16]),
+            ([13, 2], [15, 3]),
+            ([12, 14], [10, 16]),
+            ([10, 20], [10, 16]),
+            ([14, 17], [10, 16]),
+            ([8, 7], [2, 10]),
+            ([2, 0], [2, 10]),
+            ([0, 11], [2, 10]),
+            ([20, 14], [25, 17]),
+            ([17, 20], [21, 22]),
+            ([16, 7], [15, 3]),
+            ([14, 3], [15, 3]),
+            ([20, 1], [15, 3]),
+        ];
+        let balls = sensors
+            .iter()
+            .map(|(sensor, beacon)| ball(*sensor, Point(*sensor).manhattan(&Point(*beacon))))
+            .collect_vec();
+        assert_eq!(
+            uncovered_point(&balls, &Point([0, 0]), &Point([20, 20])),
+            Some(Point([14, 11]))
+        );
+    }
+
+    #[test]
+    fn uncovered_point_on_edge() {
+        let balls = [
+            ball([4, 2], 3),
+            ball([0, 0], 1),
+            ball([0, 4], 1),
+            ball([2, 0], 3),
+            ball([2, 4], 3),
+        ];
+        assert_eq!(
+            uncovered_point(&balls, &Point([0, 0]), &Point([4, 4])),
+            Some(Point([0, 2]))
+        );
+    }
+
+    #[test]
+    fn uncovered_point_in_corner() {
+        let balls = [ball([3, 0], 2), ball([0, 3], 2), ball([2, 2], 3)];
+        assert_eq!(
+            uncovered_point(&balls, &Point([0, 0]), &Point([3, 3])),
+            Some(Point([0, 0]))
+        );
+    }
+
+    #[test]
+    fn uncovered_point_brute_force() {
+        // Random balls around a free target point, until all other points within the bounds are covered
+        let mut rng = SeededRng::new(15);
+        let bound = 12;
+        for _ in 0..100 {
+            let target = Point([rng.range(0..bound + 1), rng.range(0..bound + 1)]);
+            let mut balls: Vec<Ball<2, i64>> = Vec::new();
+            loop {
+                let free = iproduct!(0..=bound, 0..=bound)
+                    .map(|(x, y)| Point([x, y]))
+                    .filter(|p| *p != target && balls.iter().all(|ball| !ball.contains(p)))
+                    .collect_vec();
+                if free.is_empty() {
+                    break;
+                }
+                let center = *rng.choose(&free) + Point([rng.range(-3..4), rng.range(-3..4)]);
+                let radius = center.manhattan(&target) - 1;
+                if radius >= 0 {
+                    balls.push(Ball::new(center, radius.min(rng.range(0..8))));
+                }
+            }
+
+            assert_eq!(
+                uncovered_point(&balls, &Point([0, 0]), &Point([bound, bound])),
+                Some(target)
+            );
+        }
+    }
+}
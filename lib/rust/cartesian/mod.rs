@@ -4,7 +4,25 @@ use std::{
     ops::Add,
 };
 
-use itertools::{iproduct, Itertools};
+use itertools::{iproduct, Either, Itertools};
+
+pub mod automaton;
+pub mod cube;
+pub mod cuboid;
+pub mod grid_graph;
+pub mod hex;
+pub mod image;
+pub mod lattice;
+pub mod manhattan;
+pub mod pattern;
+pub mod point;
+pub mod polygon;
+pub mod region;
+pub mod sparse;
+pub mod tiled;
+pub mod view;
+
+pub use sparse::SparseGrid;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
@@ -40,7 +58,7 @@ impl Direction {
             Direction::East => Direction::North,
             Direction::South => Direction::East,
             Direction::West => Direction::South,
-            Direction::None => panic!(),
+            Direction::None => Direction::None,
         }
     }
 
@@ -50,7 +68,7 @@ impl Direction {
             Direction::East => Direction::South,
             Direction::South => Direction::West,
             Direction::West => Direction::North,
-            Direction::None => panic!(),
+            Direction::None => Direction::None,
         }
     }
 }
@@ -77,6 +95,156 @@ impl TryFrom<char> for Direction {
     }
 }
 
+/// Eight directions including diagonals, in clockwise order starting north
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub fn all() -> [Self; 8] {
+        [
+            Direction8::North,
+            Direction8::NorthEast,
+            Direction8::East,
+            Direction8::SouthEast,
+            Direction8::South,
+            Direction8::SouthWest,
+            Direction8::West,
+            Direction8::NorthWest,
+        ]
+    }
+
+    pub fn diagonals() -> [Self; 4] {
+        [
+            Direction8::NorthEast,
+            Direction8::SouthEast,
+            Direction8::SouthWest,
+            Direction8::NorthWest,
+        ]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as u8 % 2 == 1
+    }
+
+    /// Rotates by the given number of 45° steps, clockwise for positive steps
+    pub fn rotate(&self, steps: i32) -> Self {
+        Self::all()[(*self as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    /// Rotates by 90° counterclockwise
+    pub fn left(&self) -> Self {
+        self.rotate(-2)
+    }
+
+    /// Rotates by 90° clockwise
+    pub fn right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn inverse(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Clockwise angle to north in degrees
+    pub fn angle(&self) -> u32 {
+        *self as u32 * 45
+    }
+
+    /// Direction for an angle (clockwise from north in degrees), which has to be a multiple of 45°
+    pub fn from_angle(degrees: i32) -> Option<Self> {
+        (degrees % 45 == 0).then(|| Direction8::North.rotate(degrees / 45))
+    }
+
+    /// Unit offset (dx, dy), y points south
+    pub fn offset(&self) -> (i128, i128) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Direction given by the signs of the offset, none for a zero offset
+    pub fn from_offset((dx, dy): (i128, i128)) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|dir| dir.offset() == (dx.signum(), dy.signum()))
+    }
+}
+
+impl TryFrom<Direction> for Direction8 {
+    type Error = ();
+
+    fn try_from(dir: Direction) -> Result<Self, Self::Error> {
+        match dir {
+            Direction::North => Ok(Direction8::North),
+            Direction::East => Ok(Direction8::East),
+            Direction::South => Ok(Direction8::South),
+            Direction::West => Ok(Direction8::West),
+            Direction::None => Err(()),
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = ();
+
+    fn try_from(dir: Direction8) -> Result<Self, Self::Error> {
+        match dir {
+            Direction8::North => Ok(Direction::North),
+            Direction8::East => Ok(Direction::East),
+            Direction8::South => Ok(Direction::South),
+            Direction8::West => Ok(Direction::West),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Compass ("N", "ne") or screen ("U", "dl") tokens, case insensitive
+impl TryFrom<&str> for Direction8 {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase().as_str() {
+            "N" | "U" | "^" => Ok(Direction8::North),
+            "NE" | "UR" => Ok(Direction8::NorthEast),
+            "E" | "R" | ">" => Ok(Direction8::East),
+            "SE" | "DR" => Ok(Direction8::SouthEast),
+            "S" | "D" | "V" => Ok(Direction8::South),
+            "SW" | "DL" => Ok(Direction8::SouthWest),
+            "W" | "L" | "<" => Ok(Direction8::West),
+            "NW" | "UL" => Ok(Direction8::NorthWest),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Order of the moore neighborhoods, kept for compatibility
+const MOORE_ORDER: [Direction8; 8] = [
+    Direction8::North,
+    Direction8::NorthWest,
+    Direction8::East,
+    Direction8::NorthEast,
+    Direction8::South,
+    Direction8::SouthEast,
+    Direction8::West,
+    Direction8::SouthWest,
+];
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Index {
     pub i: usize,
@@ -132,19 +300,20 @@ impl Index {
             .collect()
     }
 
+    /// Step in any of the eight directions, if it stays within the grid
+    pub fn advance8_check(&self, dir: Direction8, size: Size) -> Option<Self> {
+        let (dx, dy) = dir.offset();
+        let i = self.i.checked_add_signed(dx as isize).filter(|i| *i < size.width)?;
+        let j = self.j.checked_add_signed(dy as isize).filter(|j| *j < size.height)?;
+        Some(Index::new(i, j))
+    }
+
     /// 8-Neighborhood, including diagonals
     /// Order: N NW E NE S SE W SW
     pub fn moore_neighbors(&self, size: Size) -> Vec<Index> {
-        Direction::compass()
+        MOORE_ORDER
             .into_iter()
-            .flat_map(|dir| {
-                [
-                    self.advance_check(dir, size),
-                    self.advance_check(dir, size)
-                        .and_then(|next| next.advance_check(dir.left(), size)),
-                ]
-            })
-            .flatten()
+            .filter_map(|dir| self.advance8_check(dir, size))
             .collect()
     }
 
@@ -260,17 +429,14 @@ impl Position {
             .collect()
     }
 
+    pub fn advance8_by(&self, dir: Direction8, delta: i128) -> Self {
+        let (dx, dy) = dir.offset();
+        Position::new(self.x + dx * delta, self.y + dy * delta)
+    }
+
     // 8 NB, in order N NW E NE S SE W SW
     pub fn moore_neighbors(&self) -> Vec<Position> {
-        Direction::compass()
-            .into_iter()
-            .flat_map(|dir| {
-                [
-                    self.advance_by(dir, 1),
-                    self.advance_by(dir, 1).advance_by(dir.left(), 1),
-                ]
-            })
-            .collect()
+        MOORE_ORDER.into_iter().map(|dir| self.advance8_by(dir, 1)).collect()
     }
 
     pub fn wrap_modular(&self, size: Size) -> Index {
@@ -323,10 +489,54 @@ impl Size {
     }
 }
 
+/// Cell type of a grid, which is parsed from and displayed as a single char
+pub trait GridCell: Sized {
+    fn from_char(c: char) -> Self;
+    fn to_char(&self) -> char;
+}
+
+impl GridCell for char {
+    fn from_char(c: char) -> Self {
+        c
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// '#' is true, anything else false
+impl GridCell for bool {
+    fn from_char(c: char) -> Self {
+        c == '#'
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Single digits
+impl GridCell for u8 {
+    fn from_char(c: char) -> Self {
+        c.to_digit(10).expect("Cell should be a digit") as u8
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(*self as u32, 10).expect("Cell should be a single digit")
+    }
+}
+
+/// Row-major grid, stored in a single contiguous buffer
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
-    pub rows: Vec<Vec<T>>,
-    pub size: Size,
+    cells: Vec<T>,
+    /// Dimensions of the grid, the buffer always holds exactly `size.area()` cells
+    size: Size,
 }
 
 impl<T> Grid<T> {
@@ -335,33 +545,113 @@ impl<T> Grid<T> {
         T: Clone,
     {
         Grid {
-            rows: vec![vec![default; size.width]; size.height],
+            cells: vec![default; size.area()],
             size,
         }
     }
 
+    /// Creates grid from its rows, which all need to have the same length
     pub fn new(rows: Vec<Vec<T>>) -> Self {
         let size = Size {
             width: rows[0].len(),
             height: rows.len(),
         };
-        Grid { rows, size }
+        assert!(
+            rows.iter().all(|row| row.len() == size.width),
+            "All rows of a grid need to have the same length"
+        );
+
+        let mut cells = Vec::with_capacity(size.area());
+        rows.into_iter().for_each(|row| cells.extend(row));
+        Grid { cells, size }
+    }
+
+    /// Creates grid from its cells in row-major order
+    pub fn from_cells(size: Size, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), size.area(), "Number of cells doesn't match size of grid");
+        Grid { cells, size }
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    fn offset(&self, idx: &Index) -> usize {
+        assert!(
+            idx.i < self.size.width && idx.j < self.size.height,
+            "Index {idx:?} out of bounds for grid of size {:?}",
+            self.size
+        );
+        idx.j * self.size.width + idx.i
     }
 
     pub fn get(&self, idx: &Index) -> &T {
-        &self.rows[idx.j][idx.i]
+        &self.cells[self.offset(idx)]
     }
 
     pub fn get_checked(&self, idx: &Index) -> Option<&T> {
-        self.rows.get(idx.j).and_then(|r| r.get(idx.i))
+        (idx.i < self.size.width && idx.j < self.size.height).then(|| self.get(idx))
     }
 
     pub fn get_mut(&mut self, idx: &Index) -> &mut T {
-        &mut self.rows[idx.j][idx.i]
+        let offset = self.offset(idx);
+        &mut self.cells[offset]
     }
 
     pub fn set(&mut self, idx: &Index, value: T) {
-        self.rows[idx.j][idx.i] = value;
+        *self.get_mut(idx) = value;
+    }
+
+    pub fn row(&self, j: usize) -> &[T] {
+        &self.cells[j * self.size.width..(j + 1) * self.size.width]
+    }
+
+    pub fn row_mut(&mut self, j: usize) -> &mut [T] {
+        &mut self.cells[j * self.size.width..(j + 1) * self.size.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + Clone {
+        (0..self.size.height).map(|j| self.row(j))
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        // Chunks can't be empty, without columns every row is an empty slice
+        match self.size.width {
+            0 => Either::Left((0..self.size.height).map(|_| <&mut [T]>::default())),
+            width => Either::Right(self.cells.chunks_exact_mut(width)),
+        }
+    }
+
+    /// Strided view of the i-th column, top to bottom
+    pub fn column(&self, i: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone {
+        assert!(i < self.size.width);
+        self.cells[i..].iter().step_by(self.size.width)
+    }
+
+    /// Strided view of the i-th column, top to bottom
+    pub fn column_mut(&mut self, i: usize) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        assert!(i < self.size.width);
+        self.cells[i..].iter_mut().step_by(self.size.width)
+    }
+
+    /// Cells in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let width = self.size.width;
+        let mut cells = self.cells.into_iter();
+        (0..self.size.height)
+            .map(|_| cells.by_ref().take(width).collect())
+            .collect()
+    }
+
+    pub fn insert_row(&mut self, j: usize, row: Vec<T>) {
+        assert_eq!(row.len(), self.size.width, "Row needs to have the width of the grid");
+        let offset = j * self.size.width;
+        self.cells.splice(offset..offset, row);
+        self.size.height += 1;
     }
 
     pub fn corners(&self) -> [Index; 4] {
@@ -374,16 +664,15 @@ impl<T> Grid<T> {
     }
 
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
-        self.rows.iter().flatten()
+        self.cells.iter()
     }
 
     pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
-        self.rows.iter_mut().flatten()
+        self.cells.iter_mut()
     }
 
     pub fn enumerate(&self) -> impl DoubleEndedIterator<Item = (Index, &T)> {
-        self.rows
-            .iter()
+        self.rows()
             .enumerate()
             .flat_map(|(j, row)| row.iter().enumerate().map(move |(i, v)| (Index { i, j }, v)))
     }
@@ -392,22 +681,30 @@ impl<T> Grid<T> {
     where
         T: Clone,
     {
+        let Size { width, height } = self.size;
         match dir {
             Direction::North => {
-                self.rows.insert(0, vec![value; self.size.width]);
+                self.cells.splice(0..0, vec![value; width]);
                 self.size.height += 1;
             }
-            Direction::East => {
-                self.rows.iter_mut().for_each(|r| r.push(value.clone()));
-                self.size.width += 1
+            Direction::East | Direction::West => {
+                let mut cells = Vec::with_capacity((width + 1) * height);
+                // Rows are taken by index, so that grids without columns still get a cell per row
+                for row in self.rows() {
+                    if dir == Direction::West {
+                        cells.push(value.clone());
+                    }
+                    cells.extend_from_slice(row);
+                    if dir == Direction::East {
+                        cells.push(value.clone());
+                    }
+                }
+                self.cells = cells;
+                self.size.width += 1;
             }
             Direction::South => {
-                self.rows.push(vec![value; self.size.width]);
-                self.size.height += 1
-            }
-            Direction::West => {
-                self.rows.iter_mut().for_each(|r| r.insert(0, value.clone()));
-                self.size.width += 1
+                self.cells.extend(vec![value; width]);
+                self.size.height += 1;
             }
             Direction::None => (),
         }
@@ -415,60 +712,87 @@ impl<T> Grid<T> {
 
     pub fn map_elements<R, F: Fn(&T) -> R>(&self, func: F) -> Grid<R> {
         Grid {
-            rows: self
-                .rows
-                .iter()
-                .map(|row| row.iter().map(&func).collect_vec())
-                .collect_vec(),
+            cells: self.cells.iter().map(func).collect(),
             size: self.size,
         }
     }
 
     pub fn transpose(&self) -> Self
     where
-        T: Copy,
+        T: Clone,
     {
+        let Size { width, height } = self.size;
+        let mut cells = Vec::with_capacity(self.size.area());
+        for i in 0..width {
+            cells.extend(self.column(i).cloned());
+        }
+
         Grid {
-            rows: (0..self.rows[0].len())
-                .map(|i| self.rows.iter().map(|inner| inner[i]).collect())
-                .collect(),
-            size: Size {
-                width: self.size.height,
-                height: self.size.width,
-            },
+            cells,
+            size: Size::new(height, width),
         }
     }
 
+    /// Mirrors along the vertical axis, i.e. reverses each row
     pub fn flip_vertical(&self) -> Self
     where
-        T: Copy,
+        T: Clone,
     {
         Grid {
-            rows: self.rows.iter().map(|r| r.iter().rev().copied().collect()).collect(),
+            cells: self.rows().flat_map(|r| r.iter().rev().cloned()).collect(),
             size: self.size,
         }
     }
 
+    /// Mirrors along the horizontal axis, i.e. reverses the order of the rows
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.rows().rev().flatten().cloned().collect(),
+            size: self.size,
+        }
+    }
+
+    /// Rotates by 90° counterclockwise
     pub fn rotate_left(&self) -> Self
     where
-        T: Copy,
+        T: Clone,
     {
+        let Size { width, height } = self.size;
+        let mut cells = Vec::with_capacity(self.size.area());
+        for i in (0..width).rev() {
+            cells.extend(self.column(i).cloned());
+        }
+
         Grid {
-            rows: (0..self.rows[0].len())
-                .rev()
-                .map(|i| self.rows.iter().map(|inner| inner[i]).collect())
-                .collect(),
-            size: Size {
-                width: self.size.height,
-                height: self.size.width,
-            },
+            cells,
+            size: Size::new(height, width),
+        }
+    }
+
+    /// Rotates by 90° clockwise
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let Size { width, height } = self.size;
+        let mut cells = Vec::with_capacity(self.size.area());
+        for i in 0..width {
+            cells.extend(self.column(i).rev().cloned());
+        }
+
+        Grid {
+            cells,
+            size: Size::new(height, width),
         }
     }
 
     /// Creates array of all 8 symmetries of this grid / square
     pub fn symmetry_group(&self) -> [Self; 8]
     where
-        T: Copy + Debug,
+        T: Clone,
     {
         let mut symmetries = Vec::new();
         let mut clone = self.clone();
@@ -480,11 +804,11 @@ impl<T> Grid<T> {
             clone = clone.flip_vertical();
         }
 
-        symmetries.try_into().unwrap()
+        symmetries.try_into().unwrap_or_else(|_| unreachable!())
     }
 
     pub fn print<F: Fn(Index, &T) -> S, S: Display>(&self, display_fn: F) {
-        for (j, row) in self.rows.iter().enumerate() {
+        for (j, row) in self.rows().enumerate() {
             for (i, item) in row.iter().enumerate() {
                 print!("{}", display_fn(Index { i, j }, item));
             }
@@ -494,6 +818,13 @@ impl<T> Grid<T> {
     }
 }
 
+impl<T: GridCell> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = self.rows().map(|row| row.iter().map(T::to_char).collect::<String>());
+        write!(f, "{}", lines.format("\n"))
+    }
+}
+
 /// Implementation of directions in hex grid, double-height coordinates (flat side up)
 #[derive(Debug, Clone, Copy)]
 pub enum HexDirection {
@@ -0,0 +1,172 @@
+use itertools::Itertools;
+
+use super::{Grid, Index, Size};
+
+/// Occurrence of a pattern within a grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Position of the symmetry of the pattern within `symmetry_group()` which matched
+    pub symmetry: usize,
+    /// Top left corner of the matched pattern
+    pub origin: Index,
+    /// Grid cells covered by the non-wildcard cells of the pattern
+    pub cells: Vec<Index>,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// All occurrences of the pattern as is, `None` cells of the pattern match anything
+    pub fn find_pattern(&self, pattern: &Grid<Option<T>>) -> Vec<Match> {
+        self.find_symmetry(pattern, 0)
+    }
+
+    /// All occurrences of any of the 8 symmetries of the pattern, identical symmetries are only searched once
+    pub fn find_pattern_symmetries(&self, pattern: &Grid<Option<T>>) -> Vec<Match>
+    where
+        T: Clone,
+    {
+        let symmetries = pattern.symmetry_group();
+        symmetries
+            .iter()
+            .enumerate()
+            .filter(|(k, symmetry)| !symmetries[..*k].contains(symmetry))
+            .flat_map(|(k, symmetry)| self.find_symmetry(symmetry, k))
+            .collect()
+    }
+
+    fn find_symmetry(&self, pattern: &Grid<Option<T>>, symmetry: usize) -> Vec<Match> {
+        if pattern.size().width > self.size().width || pattern.size().height > self.size().height {
+            return Vec::new();
+        }
+
+        let fixed = pattern
+            .enumerate()
+            .filter_map(|(idx, cell)| cell.as_ref().map(|value| (idx, value)))
+            .collect_vec();
+        let origins = Size::new(
+            self.size().width - pattern.size().width + 1,
+            self.size().height - pattern.size().height + 1,
+        );
+
+        origins
+            .indices()
+            .filter(|origin| {
+                fixed
+                    .iter()
+                    .all(|(idx, value)| self.get(&Index::new(origin.i + idx.i, origin.j + idx.j)) == *value)
+            })
+            .map(|origin| Match {
+                symmetry,
+                origin,
+                cells: fixed
+                    .iter()
+                    .map(|(idx, _)| Index::new(origin.i + idx.i, origin.j + idx.j))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Horizontal mirror axes, given by the number of rows above them, for which exactly the given
+    /// number of cells differ from their mirror image (rows beyond the shorter side are ignored)
+    pub fn reflection_rows(&self, smudges: usize) -> Vec<usize> {
+        reflection_axes(self.size().height, smudges, |a, b| {
+            self.row(a).iter().zip(self.row(b)).filter(|(v, w)| v != w).count()
+        })
+    }
+
+    /// Vertical mirror axes, given by the number of columns left of them, see `reflection_rows`
+    pub fn reflection_columns(&self, smudges: usize) -> Vec<usize> {
+        reflection_axes(self.size().width, smudges, |a, b| {
+            self.column(a).zip(self.column(b)).filter(|(v, w)| v != w).count()
+        })
+    }
+
+    /// Smallest block whose repetition in both directions produces the grid (possibly cut off at the edges)
+    pub fn period(&self) -> Size {
+        let width = smallest_period(self.size().width, |a, b| self.column(a).eq(self.column(b)));
+        let height = smallest_period(self.size().height, |a, b| self.row(a) == self.row(b));
+        Size::new(width, height)
+    }
+}
+
+fn reflection_axes(len: usize, smudges: usize, diff: impl Fn(usize, usize) -> usize) -> Vec<usize> {
+    (1..len)
+        .filter(|&axis| {
+            let mut differences = 0;
+            for offset in 0..axis.min(len - axis) {
+                differences += diff(axis - 1 - offset, axis + offset);
+                if differences > smudges {
+                    return false;
+                }
+            }
+            differences == smudges
+        })
+        .collect()
+}
+
+fn smallest_period(len: usize, eq: impl Fn(usize, usize) -> bool) -> usize {
+    (1..=len)
+        .find(|&period| (0..len - period).all(|k| eq(k, k + period)))
+        .unwrap_or(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::new(text.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn pattern(text: &str) -> Grid<Option<char>> {
+        grid(text).map_elements(|&c| (c != '?').then_some(c))
+    }
+
+    #[test]
+    fn period() {
+        assert_eq!(grid("abab\ncdcd\nabab").period(), Size::new(2, 2));
+        assert_eq!(grid("abcab\nabcab").period(), Size::new(3, 1));
+        assert_eq!(grid("ab\nba").period(), Size::new(2, 2));
+    }
+
+    #[test]
+    fn reflections() {
+        let columns = grid("#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.");
+        assert_eq!(columns.reflection_columns(0), [5]);
+        assert_eq!(columns.reflection_rows(0), []);
+        assert_eq!(columns.reflection_columns(1), []);
+        assert_eq!(columns.reflection_rows(1), [3]);
+
+        let rows = grid("#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#");
+        assert_eq!(rows.reflection_rows(0), [4]);
+        assert_eq!(rows.reflection_columns(0), []);
+        assert_eq!(rows.reflection_rows(1), [1]);
+        assert_eq!(rows.reflection_columns(1), []);
+    }
+
+    #[test]
+    fn wildcards() {
+        let matches = grid("aa.\n.bb\n...").find_pattern(&pattern("a?\n?b"));
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].origin, Index::new(0, 0));
+        assert_eq!(matches[0].cells, [Index::new(0, 0), Index::new(1, 1)]);
+        assert_eq!(matches[1].origin, Index::new(1, 0));
+    }
+
+    #[test]
+    fn symmetries_are_searched_once() {
+        let haystack = grid("##\n#.");
+        assert_eq!(haystack.find_pattern_symmetries(&pattern("#")).len(), 3);
+
+        let matches = haystack.find_pattern_symmetries(&pattern("##"));
+        assert_eq!(matches.len(), 2);
+        let mut cells = matches.into_iter().map(|m| m.cells).collect_vec();
+        cells.sort();
+        assert_eq!(
+            cells,
+            [
+                vec![Index::new(0, 0), Index::new(1, 0)],
+                vec![Index::new(0, 0), Index::new(0, 1)]
+            ]
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use itertools::{iproduct, Itertools};
+use num::{PrimInt, Signed};
+
+use super::{Grid, Position, Size};
+
+/// Point (or vector) with N integer coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<const N: usize, T>(pub [T; N]);
+
+impl<const N: usize, T: PrimInt> Point<N, T> {
+    pub fn new(coords: [T; N]) -> Self {
+        Point(coords)
+    }
+
+    pub fn zero() -> Self {
+        Point([T::zero(); N])
+    }
+
+    /// Takes the given coordinates, all further ones are zero
+    pub fn embed(coords: &[T]) -> Self {
+        assert!(coords.len() <= N, "Too many coordinates for {N} dimensions");
+        let mut point = Self::zero();
+        point.0[..coords.len()].copy_from_slice(coords);
+        point
+    }
+
+    /// Unit vector along the given axis
+    pub fn unit(axis: usize) -> Self {
+        let mut point = Self::zero();
+        point.0[axis] = T::one();
+        point
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (0..N).fold(T::zero(), |sum, k| sum + Self::diff(self.0[k], other.0[k]))
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (0..N).fold(T::zero(), |max, k| max.max(Self::diff(self.0[k], other.0[k])))
+    }
+
+    /// Absolute difference, also for unsigned types
+    fn diff(a: T, b: T) -> T {
+        if a > b {
+            a - b
+        } else {
+            b - a
+        }
+    }
+
+    /// 2N neighbors sharing a face, in order -x +x -y +y ...
+    /// Neighbors outside the range of T (e.g. below 0 for unsigned types) are skipped.
+    pub fn von_neumann_neighbors(&self) -> Vec<Self> {
+        (0..N)
+            .flat_map(|axis| {
+                [self.0[axis].checked_sub(&T::one()), self.0[axis].checked_add(&T::one())]
+                    .into_iter()
+                    .flatten()
+                    .map(move |c| {
+                        let mut neighbor = *self;
+                        neighbor.0[axis] = c;
+                        neighbor
+                    })
+            })
+            .collect()
+    }
+
+    /// 3^N - 1 neighbors sharing at least a corner, skipping those outside the range of T
+    pub fn moore_neighbors(&self) -> Vec<Self> {
+        (0..N)
+            .map(|k| {
+                [
+                    self.0[k].checked_sub(&T::one()),
+                    Some(self.0[k]),
+                    self.0[k].checked_add(&T::one()),
+                ]
+                .into_iter()
+                .flatten()
+                .collect_vec()
+            })
+            .multi_cartesian_product()
+            .map(|coords| Point(std::array::from_fn(|k| coords[k])))
+            .filter(|p| p != self)
+            .collect()
+    }
+
+    /// Component-wise minimum
+    pub fn component_min(&self, other: &Self) -> Self {
+        Point(std::array::from_fn(|k| self.0[k].min(other.0[k])))
+    }
+
+    /// Component-wise maximum
+    pub fn component_max(&self, other: &Self) -> Self {
+        Point(std::array::from_fn(|k| self.0[k].max(other.0[k])))
+    }
+
+    /// Smallest and largest corner of the bounding box around all points
+    pub fn bounds<'a>(points: impl IntoIterator<Item = &'a Self>) -> Option<(Self, Self)>
+    where
+        T: 'a,
+    {
+        points.into_iter().fold(None, |bounds, p| match bounds {
+            None => Some((*p, *p)),
+            Some((min, max)) => Some((p.component_min(&min), p.component_max(&max))),
+        })
+    }
+
+    /// Lies within the box spanned by both corners (inclusive)
+    pub fn within(&self, min: &Self, max: &Self) -> bool {
+        (0..N).all(|k| min.0[k] <= self.0[k] && self.0[k] <= max.0[k])
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Point<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for Point<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize, T: PrimInt> Add for Point<N, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point(std::array::from_fn(|k| self.0[k] + rhs.0[k]))
+    }
+}
+
+impl<const N: usize, T: PrimInt> Sub for Point<N, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(std::array::from_fn(|k| self.0[k] - rhs.0[k]))
+    }
+}
+
+impl<const N: usize, T: PrimInt> AddAssign for Point<N, T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize, T: PrimInt> SubAssign for Point<N, T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize, T: PrimInt> Mul<T> for Point<N, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point(self.0.map(|c| c * rhs))
+    }
+}
+
+impl<const N: usize, T: PrimInt + Signed> Neg for Point<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point(self.0.map(|c| -c))
+    }
+}
+
+impl From<Position> for Point<2, i128> {
+    fn from(pos: Position) -> Self {
+        Point([pos.x, pos.y])
+    }
+}
+
+impl From<Point<2, i128>> for Position {
+    fn from(p: Point<2, i128>) -> Self {
+        Position::new(p[0], p[1])
+    }
+}
+
+/// Comma separated coordinates, as most inputs use them
+impl<const N: usize, T: Display> Display for Point<N, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.iter().join(","))
+    }
+}
+
+/// Parses comma separated coordinates, surrounding whitespace is ignored
+impl<const N: usize, T: FromStr> FromStr for Point<N, T> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords: Vec<T> = s.split(',').map(|c| c.trim().parse().map_err(|_| ())).try_collect()?;
+        Ok(Point(coords.try_into().map_err(|_| ())?))
+    }
+}
+
+/// Dense 3D grid, stored as consecutive row-major layers
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    cells: Vec<T>,
+    /// Width, height and depth of the grid (don't modify directly)
+    pub size: Point<3, usize>,
+}
+
+impl<T> Grid3<T> {
+    pub fn empty(size: Point<3, usize>, default: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid3 {
+            cells: vec![default; Self::volume_of(&size)],
+            size,
+        }
+    }
+
+    /// Stacks layers of the same size, the first one has z = 0
+    pub fn from_layers(layers: Vec<Grid<T>>) -> Self {
+        let layer_size = layers[0].size();
+        assert!(
+            layers.iter().all(|layer| layer.size() == layer_size),
+            "All layers of a grid need to have the same size"
+        );
+
+        let size = Point([layer_size.width, layer_size.height, layers.len()]);
+        let mut cells = Vec::with_capacity(Self::volume_of(&size));
+        layers
+            .into_iter()
+            .for_each(|layer| cells.extend(layer.into_rows().into_iter().flatten()));
+        Grid3 { cells, size }
+    }
+
+    fn volume_of(size: &Point<3, usize>) -> usize {
+        size.0.iter().product()
+    }
+
+    pub fn volume(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn layer_area(&self) -> usize {
+        self.size[0] * self.size[1]
+    }
+
+    fn offset(&self, p: &Point<3, usize>) -> usize {
+        assert!(
+            self.contains(p),
+            "Point {p:?} out of bounds for grid of size {:?}",
+            self.size
+        );
+        (p[2] * self.size[1] + p[1]) * self.size[0] + p[0]
+    }
+
+    pub fn contains(&self, p: &Point<3, usize>) -> bool {
+        (0..3).all(|k| p[k] < self.size[k])
+    }
+
+    pub fn get(&self, p: &Point<3, usize>) -> &T {
+        &self.cells[self.offset(p)]
+    }
+
+    pub fn get_checked(&self, p: &Point<3, usize>) -> Option<&T> {
+        self.contains(p).then(|| self.get(p))
+    }
+
+    pub fn get_mut(&mut self, p: &Point<3, usize>) -> &mut T {
+        let offset = self.offset(p);
+        &mut self.cells[offset]
+    }
+
+    pub fn set(&mut self, p: &Point<3, usize>, value: T) {
+        *self.get_mut(p) = value;
+    }
+
+    /// Cells of the z-th layer in row-major order
+    pub fn layer(&self, z: usize) -> &[T] {
+        assert!(z < self.size[2]);
+        &self.cells[z * self.layer_area()..(z + 1) * self.layer_area()]
+    }
+
+    pub fn layer_mut(&mut self, z: usize) -> &mut [T] {
+        assert!(z < self.size[2]);
+        let area = self.layer_area();
+        &mut self.cells[z * area..(z + 1) * area]
+    }
+
+    /// Copy of the z-th layer as 2D grid
+    pub fn layer_grid(&self, z: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(Size::new(self.size[0], self.size[1]), self.layer(z).to_vec())
+    }
+
+    pub fn layers(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.size[2]).map(|z| self.layer(z))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point<3, usize>> {
+        let [width, height, depth] = self.size.0;
+        iproduct!(0..depth, 0..height, 0..width).map(|(z, y, x)| Point([x, y, z]))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point<3, usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Face neighbors within the grid, in order -x +x -y +y -z +z
+    pub fn von_neumann_neighbors(&self, p: &Point<3, usize>) -> Vec<Point<3, usize>> {
+        (0..3)
+            .flat_map(|axis| {
+                let lower = p[axis].checked_sub(1).map(|c| {
+                    let mut lower = *p;
+                    lower[axis] = c;
+                    lower
+                });
+                let mut upper = *p;
+                upper[axis] += 1;
+                [lower, Some(upper)]
+            })
+            .flatten()
+            .filter(|nb| self.contains(nb))
+            .collect()
+    }
+
+    pub fn map_elements<S, F: Fn(&T) -> S>(&self, f: F) -> Grid3<S> {
+        Grid3 {
+            cells: self.cells.iter().map(f).collect(),
+            size: self.size,
+        }
+    }
+}
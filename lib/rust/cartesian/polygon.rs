@@ -0,0 +1,99 @@
+use num::Integer;
+
+use super::{Direction, Position};
+
+/// Where a point lies relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Simple polygon with lattice vertices, the closing edge from the last to the first vertex is implicit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    pub vertices: Vec<Position>,
+}
+
+impl Polygon {
+    /// Vertices in order along the boundary, a repeated first vertex at the end is dropped
+    pub fn new(mut vertices: Vec<Position>) -> Self {
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    /// Polygon traced by moving from the start, each move adds a vertex
+    pub fn from_moves(start: Position, moves: impl IntoIterator<Item = (Direction, i128)>) -> Self {
+        let mut vertices = vec![start];
+        for (dir, len) in moves {
+            let last = vertices.last().unwrap();
+            vertices.push(last.advance_by(dir, len));
+        }
+        Polygon::new(vertices)
+    }
+
+    /// All edges including the closing one
+    pub fn edges(&self) -> impl Iterator<Item = (&Position, &Position)> {
+        self.vertices.iter().zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    /// Twice the signed area (shoelace formula), which is always an integer.
+    /// Positive if the vertices run clockwise on screen, i.e. with y pointing south.
+    pub fn signed_double_area(&self) -> i128 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.signed_double_area().unsigned_abs() as f64 / 2.0
+    }
+
+    /// Number of lattice points on the edges
+    pub fn boundary_points(&self) -> u128 {
+        self.edges()
+            .map(|(a, b)| (b.x - a.x).gcd(&(b.y - a.y)).unsigned_abs())
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside (Pick's theorem), 0 for degenerate polygons without area
+    pub fn interior_points(&self) -> u128 {
+        (self.signed_double_area().unsigned_abs() + 2).saturating_sub(self.boundary_points()) / 2
+    }
+
+    /// Number of lattice points inside or on the boundary, e.g. the cells covered by a trench and its interior
+    pub fn lattice_points(&self) -> u128 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn locate(&self, p: &Position) -> Location {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+            let within = a.x.min(b.x) <= p.x && p.x <= a.x.max(b.x) && a.y.min(b.y) <= p.y && p.y <= a.y.max(b.y);
+            if cross == 0 && within {
+                return Location::Boundary;
+            }
+
+            // Ray casting towards +x, each edge includes its lower end only so vertices are counted once
+            if (a.y > p.y) != (b.y > p.y) {
+                let crosses_right = if b.y > a.y { cross > 0 } else { cross < 0 };
+                if crosses_right {
+                    inside = !inside;
+                }
+            }
+        }
+
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// Inside or on the boundary
+    pub fn contains(&self, p: &Position) -> bool {
+        self.locate(p) != Location::Outside
+    }
+}
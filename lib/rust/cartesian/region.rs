@@ -0,0 +1,137 @@
+use std::collections::{HashSet, VecDeque};
+
+use itertools::Itertools;
+
+use super::{Direction, Grid, Index, Position, Size};
+
+/// 4-connected set of cells in a grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Cells in order of discovery, starting from the first one
+    pub cells: Vec<Index>,
+    members: HashSet<Index>,
+    grid_size: Size,
+}
+
+impl Region {
+    fn new(cells: Vec<Index>, grid_size: Size) -> Self {
+        Region {
+            members: cells.iter().copied().collect(),
+            cells,
+            grid_size,
+        }
+    }
+
+    pub fn contains(&self, idx: &Index) -> bool {
+        self.members.contains(idx)
+    }
+
+    /// Also works for positions outside of the grid
+    fn contains_position(&self, pos: Position) -> bool {
+        pos.x >= 0 && pos.y >= 0 && self.members.contains(&pos.into())
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell edges bordering other cells or the outside of the grid
+    pub fn perimeter(&self) -> usize {
+        let inner_edges: usize = self
+            .cells
+            .iter()
+            .map(|idx| {
+                idx.von_neumann_neighbors(self.grid_size)
+                    .iter()
+                    .filter(|nb| self.contains(nb))
+                    .count()
+            })
+            .sum();
+        4 * self.area() - inner_edges
+    }
+
+    /// Number of convex and concave corners, including those of holes
+    pub fn corners(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|idx| {
+                let pos = Position::from(*idx);
+                Direction::compass()
+                    .into_iter()
+                    .filter(|dir| {
+                        let side = self.contains_position(pos.advance_by(*dir, 1));
+                        let other_side = self.contains_position(pos.advance_by(dir.right(), 1));
+                        let diagonal = self.contains_position(pos.advance_by(*dir, 1).advance_by(dir.right(), 1));
+                        (!side && !other_side) || (side && other_side && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight fence sides, which always equals the number of corners
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// Top left and bottom right corner of the bounding box
+    pub fn bounds(&self) -> Option<(Index, Index)> {
+        let (min_i, max_i) = self.cells.iter().map(|idx| idx.i).minmax().into_option()?;
+        let (min_j, max_j) = self.cells.iter().map(|idx| idx.j).minmax().into_option()?;
+        Some((Index::new(min_i, min_j), Index::new(max_i, max_j)))
+    }
+
+    /// At least one cell lies on the border of the grid
+    pub fn touches_border(&self) -> bool {
+        self.cells.iter().any(|idx| {
+            idx.i == 0 || idx.j == 0 || idx.i + 1 == self.grid_size.width || idx.j + 1 == self.grid_size.height
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// All cells connected to the start, which satisfy the predicate (empty if the start itself doesn't)
+    pub fn flood_fill<F: Fn(&T) -> bool>(&self, start: &Index, predicate: F) -> Region {
+        let mut visited = Grid::empty(self.size(), false);
+        self.fill(start, &predicate, &mut visited)
+    }
+
+    /// Splits the grid into connected regions of cells with equal key, in order of their first cell
+    pub fn regions_by<K: PartialEq, F: Fn(&T) -> K>(&self, key: F) -> Vec<(K, Region)> {
+        let mut visited = Grid::empty(self.size(), false);
+        let mut regions = Vec::new();
+
+        for (idx, value) in self.enumerate() {
+            if *visited.get(&idx) {
+                continue;
+            }
+
+            let region_key = key(value);
+            let region = self.fill(&idx, &|other| key(other) == region_key, &mut visited);
+            regions.push((region_key, region));
+        }
+
+        regions
+    }
+
+    fn fill<F: Fn(&T) -> bool>(&self, start: &Index, predicate: &F, visited: &mut Grid<bool>) -> Region {
+        let mut cells = Vec::new();
+        if !predicate(self.get(start)) {
+            return Region::new(cells, self.size());
+        }
+
+        let mut queue = VecDeque::from([*start]);
+        visited.set(start, true);
+        while let Some(idx) = queue.pop_front() {
+            cells.push(idx);
+            for nb in idx.von_neumann_neighbors(self.size()) {
+                if !visited.get(&nb) && predicate(self.get(&nb)) {
+                    visited.set(&nb, true);
+                    queue.push_back(nb);
+                }
+            }
+        }
+
+        Region::new(cells, self.size())
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use super::{Grid, GridCell, Position, Size};
+
+/// Unbounded grid keyed by position, storing only cells which have been set explicitly.
+/// All other cells have the default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Position, T>,
+    default: T,
+    /// Smallest and largest corner of the bounding box around all set cells
+    bounds: Option<(Position, Position)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Stores all cells of the dense grid which differ from the default, index (0, 0) is mapped to the origin
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut sparse = SparseGrid::new(default);
+        for (idx, value) in grid.enumerate() {
+            if *value != sparse.default {
+                sparse.set(&idx.into(), value.clone());
+            }
+        }
+        sparse
+    }
+
+    /// Dense grid covering the bounding box, together with the position of its top left corner
+    pub fn to_grid(&self) -> (Grid<T>, Position)
+    where
+        T: Clone,
+    {
+        let Some((min, _)) = self.bounds else {
+            return (Grid::from_cells(Size::new(0, 0), vec![]), Position::zero());
+        };
+
+        let mut grid = Grid::empty(self.size(), self.default.clone());
+        for (pos, value) in &self.cells {
+            grid.set(&Position::new(pos.x - min.x, pos.y - min.y).into(), value.clone());
+        }
+        (grid, min)
+    }
+
+    pub fn get(&self, pos: &Position) -> &T {
+        self.cells.get(pos).unwrap_or(&self.default)
+    }
+
+    /// Value of the cell, only if it has been set
+    pub fn get_checked(&self, pos: &Position) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    /// Sets the cell to the default value first, if it hasn't been set yet
+    pub fn get_mut(&mut self, pos: &Position) -> &mut T
+    where
+        T: Clone,
+    {
+        self.include(pos);
+        self.cells.entry(*pos).or_insert_with(|| self.default.clone())
+    }
+
+    pub fn set(&mut self, pos: &Position, value: T) {
+        self.include(pos);
+        self.cells.insert(*pos, value);
+    }
+
+    pub fn remove(&mut self, pos: &Position) -> Option<T> {
+        let value = self.cells.remove(pos)?;
+
+        // Bounding box only needs to be recomputed if the cell was on its border
+        if let Some((min, max)) = self.bounds {
+            if [min.x, max.x].contains(&pos.x) || [min.y, max.y].contains(&pos.y) {
+                self.bounds = None;
+                for p in self.cells.keys().copied().collect_vec() {
+                    self.include(&p);
+                }
+            }
+        }
+        Some(value)
+    }
+
+    fn include(&mut self, pos: &Position) {
+        self.bounds = Some(match self.bounds {
+            None => (*pos, *pos),
+            Some((min, max)) => (
+                Position::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Position::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ),
+        });
+    }
+
+    pub fn contains(&self, pos: &Position) -> bool {
+        self.cells.contains_key(pos)
+    }
+
+    /// Number of set cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Smallest and largest corner of the bounding box around all set cells
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        self.bounds
+    }
+
+    /// Size of the bounding box
+    pub fn size(&self) -> Size {
+        self.bounds.map_or(Size::new(0, 0), |(min, max)| {
+            Size::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize)
+        })
+    }
+
+    /// Set cells, in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&Position, &T)> {
+        self.cells.iter()
+    }
+
+    /// 4-Neighborhood with values, in order N E S W
+    pub fn von_neumann_neighbors(&self, pos: &Position) -> Vec<(Position, &T)> {
+        pos.von_neumann_neighbors(1)
+            .into_iter()
+            .map(|p| (p, self.get(&p)))
+            .collect()
+    }
+
+    /// 8-Neighborhood with values, in order N NW E NE S SE W SW
+    pub fn moore_neighbors(&self, pos: &Position) -> Vec<(Position, &T)> {
+        pos.moore_neighbors().into_iter().map(|p| (p, self.get(&p))).collect()
+    }
+
+    /// Prints the bounding box
+    pub fn print<F: Fn(Position, &T) -> S, S: Display>(&self, display_fn: F) {
+        if let Some((min, max)) = self.bounds {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    let pos = Position::new(x, y);
+                    print!("{}", display_fn(pos, self.get(&pos)));
+                }
+                println!();
+            }
+        }
+        println!();
+    }
+}
+
+impl<T: Default> FromIterator<(Position, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Position, T)>>(iter: I) -> Self {
+        let mut sparse = SparseGrid::new(T::default());
+        for (pos, value) in iter {
+            sparse.set(&pos, value);
+        }
+        sparse
+    }
+}
+
+impl<T: GridCell> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+
+        let lines = (min.y..=max.y).map(|y| {
+            (min.x..=max.x)
+                .map(|x| self.get(&Position::new(x, y)).to_char())
+                .collect::<String>()
+        });
+        write!(f, "{}", lines.format("\n"))
+    }
+}
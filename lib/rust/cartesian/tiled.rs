@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use super::grid_graph::{breadth_first, Connectivity, GridGraph, Visits};
+use super::{Index, Position, Size};
+use crate::graph::PathFinding;
+
+/// View of a grid graph as infinite plane, which is covered by copies (tiles) of the grid.
+/// The tile containing the origin is the grid itself, tiles are numbered by their offset from it.
+pub struct TiledGrid<'a, T, P, C> {
+    pub graph: GridGraph<'a, T, P, C>,
+}
+
+impl<'a, T, P, C> GridGraph<'a, T, P, C>
+where
+    P: Fn(&T, &T) -> bool,
+    C: Fn(&T, &T) -> i64,
+{
+    /// Moves between the cells of the graph, which continue into the neighboring tiles at the edges
+    pub fn tiled(self) -> TiledGrid<'a, T, P, C> {
+        TiledGrid { graph: self }
+    }
+}
+
+impl<T, P, C> TiledGrid<'_, T, P, C>
+where
+    P: Fn(&T, &T) -> bool,
+    C: Fn(&T, &T) -> i64,
+{
+    pub fn size(&self) -> Size {
+        self.graph.grid.size()
+    }
+
+    pub fn get(&self, pos: &Position) -> &T {
+        self.graph.grid.get(&self.local(pos))
+    }
+
+    /// Cell of the grid the position corresponds to
+    pub fn local(&self, pos: &Position) -> Index {
+        pos.wrap_modular(self.size())
+    }
+
+    /// Tile the position falls in, e.g. (-1, 0) for the copy left of the grid
+    pub fn tile(&self, pos: &Position) -> Position {
+        let Size { width, height } = self.size();
+        Position::new(pos.x.div_euclid(width as i128), pos.y.div_euclid(height as i128))
+    }
+
+    /// Inverse of `tile` and `local`
+    pub fn position(&self, tile: &Position, local: &Index) -> Position {
+        let Size { width, height } = self.size();
+        Position::new(
+            tile.x * width as i128 + local.i as i128,
+            tile.y * height as i128 + local.j as i128,
+        )
+    }
+
+    /// Positions reachable in a single move
+    pub fn neighbors(&self, pos: &Position) -> Vec<Position> {
+        let candidates = match self.graph.connectivity {
+            Connectivity::VonNeumann => pos.von_neumann_neighbors(1),
+            Connectivity::Moore => pos.moore_neighbors(),
+        };
+
+        let from = self.get(pos);
+        candidates
+            .into_iter()
+            .filter(|nb| (self.graph.passable)(from, self.get(nb)))
+            .collect()
+    }
+
+    /// Number of moves from the start to each position reachable within the maximum distance, ignoring costs
+    pub fn distances(&self, start: &Position, max_dist: usize) -> HashMap<Position, usize> {
+        self.multi_source_distances([*start], max_dist)
+    }
+
+    /// Number of moves from the closest start to each position reachable within the maximum distance, ignoring costs
+    pub fn multi_source_distances(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        max_dist: usize,
+    ) -> HashMap<Position, usize> {
+        let mut distances = HashMap::new();
+        breadth_first(&mut distances, starts, |pos| self.neighbors(pos), None, Some(max_dist));
+        distances
+    }
+}
+
+impl Visits<Position> for HashMap<Position, usize> {
+    fn is_visited(&self, pos: &Position) -> bool {
+        self.contains_key(pos)
+    }
+
+    fn visit(&mut self, pos: Position, dist: usize, _: Option<Position>) {
+        self.insert(pos, dist);
+    }
+}
+
+impl<T, P, C> PathFinding<Position> for TiledGrid<'_, T, P, C>
+where
+    P: Fn(&T, &T) -> bool,
+    C: Fn(&T, &T) -> i64,
+{
+    fn transitions(&mut self, current: &Position) -> Vec<(Position, i64)> {
+        let from = self.get(current);
+        self.neighbors(current)
+            .into_iter()
+            .map(|nb| (nb, (self.graph.cost)(from, self.get(&nb))))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::Grid;
+
+    #[test]
+    fn distances_across_tiles() {
+        let grid = Grid::new(vec![".#".chars().collect(), "..".chars().collect()]);
+        let tiled = GridGraph::new(&grid).passable(|_, to| *to != '#').tiled();
+
+        let distances = tiled.distances(&Position::new(0, 0), 3);
+        assert_eq!(distances.len(), 15);
+        assert_eq!(distances[&Position::new(0, -3)], 3);
+        assert_eq!(distances[&Position::new(-2, -1)], 3);
+        assert!(!distances.contains_key(&Position::new(-1, 0)));
+        assert_eq!(tiled.tile(&Position::new(-2, -1)), Position::new(-1, -1));
+        assert_eq!(tiled.local(&Position::new(-2, -1)), Index::new(0, 1));
+    }
+}
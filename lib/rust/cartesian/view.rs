@@ -0,0 +1,169 @@
+use super::{Grid, Index, Size};
+
+/// Borrowed rectangular part of a grid, indices are relative to its top left corner
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Index,
+    pub size: Size,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn get(&self, idx: &Index) -> &'a T {
+        self.get_checked(idx)
+            .unwrap_or_else(|| panic!("Index {idx:?} out of bounds for view of size {:?}", self.size))
+    }
+
+    pub fn get_checked(&self, idx: &Index) -> Option<&'a T> {
+        (idx.i < self.size.width && idx.j < self.size.height)
+            .then(|| self.grid.get(&Index::new(self.origin.i + idx.i, self.origin.j + idx.j)))
+    }
+
+    pub fn row(&self, j: usize) -> &'a [T] {
+        assert!(j < self.size.height);
+        &self.grid.row(self.origin.j + j)[self.origin.i..self.origin.i + self.size.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + ExactSizeIterator + '_ {
+        (0..self.size.height).map(|j| self.row(j))
+    }
+
+    /// Cells in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.rows().flatten()
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Index, &'a T)> + '_ {
+        self.rows()
+            .enumerate()
+            .flat_map(|(j, row)| row.iter().enumerate().map(move |(i, v)| (Index { i, j }, v)))
+    }
+
+    /// View of a part of this view, relative to its top left corner
+    pub fn view(&self, origin: Index, size: Size) -> GridView<'a, T> {
+        assert!(
+            origin.i + size.width <= self.size.width && origin.j + size.height <= self.size.height,
+            "View of size {size:?} at {origin:?} exceeds view of size {:?}",
+            self.size
+        );
+        GridView {
+            grid: self.grid,
+            origin: Index::new(self.origin.i + origin.i, self.origin.j + origin.j),
+            size,
+        }
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.size.area());
+        self.rows().for_each(|row| cells.extend_from_slice(row));
+        Grid::from_cells(self.size, cells)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Borrowed part of the grid, with the given top left corner and size
+    pub fn view(&self, origin: Index, size: Size) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            origin: Index::new(0, 0),
+            size: self.size(),
+        }
+        .view(origin, size)
+    }
+
+    /// Copy of a part of the grid, with the given top left corner and size
+    pub fn crop(&self, origin: Index, size: Size) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.view(origin, size).to_grid()
+    }
+
+    /// Splits the grid into equally sized tiles, which need to divide it exactly
+    pub fn split_into_tiles(&self, tile_size: Size) -> Grid<Grid<T>>
+    where
+        T: Clone,
+    {
+        assert!(
+            tile_size.width > 0 && tile_size.height > 0,
+            "Tiles need a non-zero size, got {tile_size:?}"
+        );
+        assert!(
+            self.size().width.is_multiple_of(tile_size.width) && self.size().height.is_multiple_of(tile_size.height),
+            "Tiles of size {tile_size:?} don't divide grid of size {:?}",
+            self.size()
+        );
+
+        let tiled_size = Size::new(
+            self.size().width / tile_size.width,
+            self.size().height / tile_size.height,
+        );
+        let tiles = (0..tiled_size.height)
+            .flat_map(|tj| (0..tiled_size.width).map(move |ti| (ti, tj)))
+            .map(|(ti, tj)| self.crop(Index::new(ti * tile_size.width, tj * tile_size.height), tile_size))
+            .collect();
+        Grid::from_cells(tiled_size, tiles)
+    }
+
+    /// Stitches a grid of equally sized tiles together, inverse of `split_into_tiles`
+    pub fn from_tiles(tiles: Grid<Grid<T>>) -> Self {
+        let Some(tile_size) = tiles.iter().next().map(Grid::size) else {
+            return Grid::from_cells(Size::new(0, 0), Vec::new());
+        };
+        assert!(
+            tiles.iter().all(|tile| tile.size() == tile_size),
+            "All tiles need to have the same size"
+        );
+
+        let size = Size::new(
+            tiles.size().width * tile_size.width,
+            tiles.size().height * tile_size.height,
+        );
+        let mut cells = Vec::with_capacity(size.area());
+        for tile_row in tiles.into_rows() {
+            // Take the next row of each tile in turn
+            let mut tile_cells = tile_row
+                .into_iter()
+                .map(|tile| tile.cells.into_iter())
+                .collect::<Vec<_>>();
+            for _ in 0..tile_size.height {
+                for cells_of_tile in tile_cells.iter_mut() {
+                    cells.extend(cells_of_tile.by_ref().take(tile_size.width));
+                }
+            }
+        }
+        Grid::from_cells(size, cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiles_round_trip() {
+        let grid = Grid::from_cells(Size::new(6, 4), (0..24).collect());
+        let tiles = grid.split_into_tiles(Size::new(3, 2));
+        assert_eq!(tiles.size(), Size::new(2, 2));
+        assert_eq!(
+            tiles.get(&Index::new(1, 0)).rows().collect::<Vec<_>>(),
+            [[3, 4, 5], [9, 10, 11]]
+        );
+        assert_eq!(Grid::from_tiles(tiles), grid);
+    }
+
+    #[test]
+    fn from_no_tiles() {
+        let tiles: Grid<Grid<u8>> = Grid::from_cells(Size::new(0, 0), Vec::new());
+        assert_eq!(Grid::from_tiles(tiles).size(), Size::new(0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Tiles need a non-zero size")]
+    fn split_into_empty_tiles() {
+        Grid::from_cells(Size::new(2, 2), vec![0; 4]).split_into_tiles(Size::new(0, 2));
+    }
+}
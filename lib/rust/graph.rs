@@ -318,6 +318,60 @@ pub trait PathFinding<V: Clone + Eq + Hash + Ord> {
 
         paths
     }
+
+    /// Contracts the graph to the kept vertices. Each of them is connected to all kept vertices,
+    /// which are reachable without passing another kept vertex, weighted by the shortest such path.
+    /// Directed transitions (e.g. one-way cells) result in directed edges, of parallel paths only the shortest is kept.
+    fn contract(&mut self, keep: &[V]) -> Contraction<V> {
+        let kept: HashSet<_> = keep.iter().collect();
+        let mut contraction = Contraction {
+            graph: Graph::empty(),
+            paths: HashMap::new(),
+        };
+
+        for start in keep {
+            let mut prev: HashMap<V, V> = HashMap::new();
+            let mut dist = HashMap::from([(start.clone(), 0)]);
+            let mut queue = BinaryHeap::from([(Reverse(0), start.clone())]);
+
+            while let Some((Reverse(d), u)) = queue.pop() {
+                if d > dist[&u] {
+                    continue;
+                }
+
+                // Reached another kept vertex, don't walk past it
+                if u != *start && kept.contains(&u) {
+                    let mut path = vec![u.clone()];
+                    while let Some(p) = prev.get(path.last().unwrap()) {
+                        path.push(p.clone());
+                    }
+                    path.reverse();
+
+                    contraction.graph.add_weighted_edge(start, &u, d, true);
+                    contraction.paths.insert((start.clone(), u), path);
+                    continue;
+                }
+
+                for (v, weight) in self.transitions(&u) {
+                    let alt = d + weight;
+                    if alt < *dist.get(&v).unwrap_or(&i64::MAX) {
+                        dist.insert(v.clone(), alt);
+                        prev.insert(v.clone(), u.clone());
+                        queue.push((Reverse(alt), v));
+                    }
+                }
+            }
+        }
+
+        contraction
+    }
+}
+
+/// Graph between kept vertices, together with the original path (including both ends) each edge replaces
+#[derive(Debug, Clone)]
+pub struct Contraction<V> {
+    pub graph: Graph<V>,
+    pub paths: HashMap<(V, V), Vec<V>>,
 }
 
 impl<V: Clone + Eq + Hash + Ord> PathFinding<V> for Graph<V> {
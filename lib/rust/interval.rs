@@ -0,0 +1,357 @@
+use std::ops::{Range, RangeInclusive};
+
+use num::PrimInt;
+
+/// Closed integer interval [start, end], empty if start > end.
+/// Stored inclusively, so that intervals reaching up to the maximum of T are representable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn closed(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// [start, end), empty if end <= start
+    pub fn half_open(start: T, end: T) -> Self {
+        if end <= start {
+            Self::empty()
+        } else {
+            Interval::closed(start, end - T::one())
+        }
+    }
+
+    /// Interval of the given length, starting at start
+    pub fn with_len(start: T, len: T) -> Self {
+        if len.is_zero() {
+            Self::empty()
+        } else {
+            Interval::closed(start, start + (len - T::one()))
+        }
+    }
+
+    pub fn point(value: T) -> Self {
+        Interval::closed(value, value)
+    }
+
+    pub fn empty() -> Self {
+        Interval::closed(T::one(), T::zero())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Number of contained values, which has to fit into T
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start + T::one()
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Interval::closed(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Overlapping or directly adjacent, so that the union is an interval again
+    fn touches(&self, other: &Self) -> bool {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        second.start <= first.end || first.end.checked_add(&T::one()) == Some(second.start)
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::half_open(range.start, range.end)
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Interval::closed(*range.start(), *range.end())
+    }
+}
+
+/// Set of integers, stored as sorted disjoint intervals, which are neither empty nor adjacent
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { intervals: Vec::new() }
+    }
+
+    /// Merges overlapping and adjacent intervals
+    fn normalized(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by_key(|interval| interval.start);
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if last.touches(&interval) => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        RangeSet { intervals: merged }
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::normalized(intervals);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of contained values, which has to fit into T
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |len, interval| len + interval.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|interval| interval.end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.interval_of(value).is_some()
+    }
+
+    /// Interval of the set containing the value
+    pub fn interval_of(&self, value: T) -> Option<&Interval<T>> {
+        let k = self.intervals.partition_point(|interval| interval.end < value);
+        self.intervals.get(k).filter(|interval| interval.contains(value))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized(self.intervals.iter().chain(&other.intervals).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (left, right) = (&self.intervals[a], &other.intervals[b]);
+            intervals.push(left.intersection(right));
+            if left.end < right.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        Self::normalized(intervals)
+    }
+
+    /// All values within the bounds, which are not contained
+    pub fn complement(&self, bounds: Interval<T>) -> Self {
+        let mut gaps = Vec::new();
+        let mut next = Some(bounds.start);
+        for interval in &self.intervals {
+            let Some(start) = next else { break };
+            if interval.end < start {
+                continue;
+            }
+            if interval.start > start {
+                gaps.push(Interval::closed(start, interval.start - T::one()));
+            }
+            next = interval.end.checked_add(&T::one());
+        }
+        if let Some(start) = next {
+            gaps.push(Interval::closed(start, bounds.end));
+        }
+
+        Self::normalized(gaps.into_iter().map(|gap| gap.intersection(&bounds)).collect())
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.min(), self.max()) {
+            (Some(min), Some(max)) => self.intersection(&other.complement(Interval::closed(min, max))),
+            _ => Self::new(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: PrimInt> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalized(vec![interval])
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().collect())
+    }
+}
+
+/// Piecewise translation of integers: each source interval is moved so that it starts at its target,
+/// all other values are left unchanged. Source intervals must not overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: PrimInt> OffsetMap<T> {
+    pub fn new() -> Self {
+        OffsetMap { pieces: Vec::new() }
+    }
+
+    pub fn insert(&mut self, source: Interval<T>, target: T) {
+        assert!(
+            self.pieces.iter().all(|(other, _)| !other.overlaps(&source)),
+            "Source intervals of an offset map must not overlap"
+        );
+        self.pieces.push((source, target));
+    }
+
+    pub fn apply(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(source, target)| *target + (value - source.start))
+    }
+
+    /// Image of all values in the set, computed interval-wise
+    pub fn apply_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let sources: RangeSet<T> = self.pieces.iter().map(|(source, _)| *source).collect();
+        let moved = self.pieces.iter().flat_map(|(source, target)| {
+            set.intersection(&(*source).into()).intervals.into_iter().map(|part| {
+                let start = *target + (part.start - source.start);
+                Interval::with_len(start, part.len())
+            })
+        });
+
+        set.difference(&sources).intervals.into_iter().chain(moved).collect()
+    }
+}
+
+impl<T: PrimInt> Default for OffsetMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt> FromIterator<(Interval<T>, T)> for OffsetMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, T)>>(iter: I) -> Self {
+        let mut map = OffsetMap::new();
+        iter.into_iter().for_each(|(source, target)| map.insert(source, target));
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set<T: PrimInt>(intervals: &[(T, T)]) -> RangeSet<T> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::closed(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn merges() {
+        let merged = set(&[(5, 7), (1, 2), (3, 4), (6, 9), (12, 11), (20, 20)]);
+        assert_eq!(merged.intervals(), set(&[(1, 9), (20, 20)]).intervals());
+        assert_eq!(merged.len(), 10);
+
+        let separate = set(&[(1, 2), (4, 5)]);
+        assert_eq!(separate.intervals().len(), 2);
+    }
+
+    #[test]
+    fn merges_at_bounds() {
+        let merged = set(&[(u8::MAX, u8::MAX), (0, 0), (1, 254)]);
+        assert_eq!(merged, set(&[(0, u8::MAX)]));
+    }
+
+    #[test]
+    fn complement() {
+        let values = set(&[(3, 5), (8, 8)]);
+        assert_eq!(
+            values.complement(Interval::closed(0, 10)),
+            set(&[(0, 2), (6, 7), (9, 10)])
+        );
+        assert_eq!(values.complement(Interval::closed(4, 8)), set(&[(6, 7)]));
+    }
+
+    #[test]
+    fn complement_at_type_bounds() {
+        let full = Interval::closed(i8::MIN, i8::MAX);
+        assert_eq!(set(&[(i8::MIN, -1)]).complement(full), set(&[(0, i8::MAX)]));
+        assert_eq!(set(&[(0, i8::MAX)]).complement(full), set(&[(i8::MIN, -1)]));
+        assert!(set(&[(i8::MIN, i8::MAX)]).complement(full).is_empty());
+        assert_eq!(RangeSet::new().complement(full), set(&[(i8::MIN, i8::MAX)]));
+    }
+
+    #[test]
+    fn intersection() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (15, 20)]);
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12), (15, 15)]));
+    }
+
+    #[test]
+    fn difference_with_points() {
+        let row = set(&[(-2, 24)]);
+        let beacons = set(&[(2, 2), (24, 24), (30, 30)]);
+        assert_eq!(row.difference(&beacons), set(&[(-2, 1), (3, 23)]));
+        assert_eq!(row.difference(&beacons).len(), 25);
+        assert_eq!(beacons.difference(&row), set(&[(30, 30)]));
+    }
+
+    #[test]
+    fn apply_set_splits_ranges() {
+        let map: OffsetMap<i64> = [(Interval::closed(98, 99), 50), (Interval::closed(50, 97), 52)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.apply(79), 81);
+        assert_eq!(map.apply(99), 51);
+        assert_eq!(map.apply(10), 10);
+
+        // 90..=99 is split across both pieces, 100..=104 is not mapped
+        let image = map.apply_set(&set(&[(90, 104)]));
+        assert_eq!(image, set(&[(50, 51), (92, 99), (100, 104)]));
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt::{self, Display};
+
+use itertools::Itertools;
+
+use crate::cartesian::{Grid, Index, Position, Size};
+
+/// Letters of the font which is 6 cells high, each 4 cells wide (except Y) with one empty column in between
+const SMALL_FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters of the font which is 10 cells high, each 6 cells wide with two empty columns in between
+const LARGE_FONT: [(char, [&str; 10]); 15] = [
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######",
+        ],
+    ),
+];
+
+/// Text which contains glyphs that are not part of the fonts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrError {
+    /// Recognized text, with '?' for each unknown glyph
+    pub text: String,
+    pub unknown: Vec<Grid<bool>>,
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown glyphs in '{}':", self.text)?;
+        self.unknown.iter().try_for_each(|glyph| write!(f, "\n\n{glyph}"))
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Reads the letters formed by the true cells, wherever they are located in the grid
+pub fn read_grid(grid: &Grid<bool>) -> Result<String, OcrError> {
+    let lit = grid
+        .enumerate()
+        .filter(|(_, lit)| **lit)
+        .map(|(idx, _)| idx.into())
+        .collect_vec();
+    read_positions(&lit)
+}
+
+/// Reads the letters formed by the positions, which only need to be in the right place relative to each other
+pub fn read_positions<'a>(positions: impl IntoIterator<Item = &'a Position>) -> Result<String, OcrError> {
+    let positions = positions.into_iter().collect_vec();
+    let Some((min_x, max_x)) = positions.iter().map(|pos| pos.x).minmax().into_option() else {
+        return Ok(String::new());
+    };
+    let (min_y, max_y) = positions.iter().map(|pos| pos.y).minmax().into_option().unwrap();
+
+    // The text as list of columns, which only contain the rows with letters
+    let size = Size::new((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+    let mut columns = vec![vec![false; size.height]; size.width];
+    for pos in positions {
+        columns[(pos.x - min_x) as usize][(pos.y - min_y) as usize] = true;
+    }
+
+    let font = match size.height {
+        6 => SMALL_FONT
+            .iter()
+            .map(|(letter, rows)| (*letter, glyph_columns(rows)))
+            .collect_vec(),
+        10 => LARGE_FONT
+            .iter()
+            .map(|(letter, rows)| (*letter, glyph_columns(rows)))
+            .collect_vec(),
+        _ => {
+            return Err(OcrError {
+                text: "?".to_string(),
+                unknown: vec![columns_to_grid(&columns)],
+            })
+        }
+    };
+
+    let is_blank = |column: &[bool]| column.iter().all(|lit| !lit);
+    let (mut text, mut unknown) = (String::new(), Vec::new());
+    let mut i = 0;
+    while i < columns.len() {
+        if is_blank(&columns[i]) {
+            i += 1;
+            continue;
+        }
+
+        // Letters may touch the next one, so take the widest matching glyph
+        let letter = font
+            .iter()
+            .filter(|(_, glyph)| columns[i..].starts_with(glyph))
+            .max_by_key(|(_, glyph)| glyph.len());
+        match letter {
+            Some((letter, glyph)) => {
+                text.push(*letter);
+                i += glyph.len();
+            }
+            None => {
+                let end = (i..columns.len())
+                    .find(|&k| is_blank(&columns[k]))
+                    .unwrap_or(columns.len());
+                text.push('?');
+                unknown.push(columns_to_grid(&columns[i..end]));
+                i = end;
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError { text, unknown })
+    }
+}
+
+/// Columns of a glyph without the empty ones on either side
+fn glyph_columns(rows: &[&str]) -> Vec<Vec<bool>> {
+    let columns = (0..rows[0].len())
+        .map(|i| rows.iter().map(|row| row.as_bytes()[i] == b'#').collect_vec())
+        .collect_vec();
+    let first = columns.iter().position(|column| column.contains(&true)).unwrap();
+    let last = columns.iter().rposition(|column| column.contains(&true)).unwrap();
+    columns[first..=last].to_vec()
+}
+
+fn columns_to_grid(columns: &[Vec<bool>]) -> Grid<bool> {
+    let size = Size::new(columns.len(), columns[0].len());
+    let mut grid = Grid::empty(size, false);
+    for (i, column) in columns.iter().enumerate() {
+        for (j, lit) in column.iter().enumerate() {
+            grid.set(&Index::new(i, j), *lit);
+        }
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Screen showing the text in the font, with the given number of empty columns between letters
+    fn render<const H: usize>(font: &[(char, [&str; H])], text: &str, gap: usize) -> Grid<bool> {
+        let rows = (0..H)
+            .map(|j| {
+                text.chars()
+                    .map(|c| font.iter().find(|(letter, _)| *letter == c).unwrap().1[j])
+                    .join(&".".repeat(gap))
+            })
+            .collect_vec();
+        screen(&rows)
+    }
+
+    fn screen(rows: &[String]) -> Grid<bool> {
+        Grid::new(rows.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect())
+    }
+
+    #[test]
+    fn small_font() {
+        let text = SMALL_FONT.iter().map(|(letter, _)| letter).collect::<String>();
+        assert_eq!(read_grid(&render(&SMALL_FONT, &text, 1)), Ok(text));
+    }
+
+    #[test]
+    fn large_font() {
+        let text = LARGE_FONT.iter().map(|(letter, _)| letter).collect::<String>();
+        assert_eq!(read_grid(&render(&LARGE_FONT, &text, 2)), Ok(text));
+    }
+
+    #[test]
+    fn wide_letter() {
+        assert_eq!(read_grid(&render(&SMALL_FONT, "YAY", 1)), Ok("YAY".to_string()));
+    }
+
+    #[test]
+    fn touching_letters() {
+        assert_eq!(read_grid(&render(&SMALL_FONT, "HELP", 0)), Ok("HELP".to_string()));
+        assert_eq!(read_grid(&render(&LARGE_FONT, "HELP", 0)), Ok("HELP".to_string()));
+    }
+
+    #[test]
+    fn offset_positions() {
+        let screen = render(&SMALL_FONT, "OK", 1);
+        let lit = screen
+            .enumerate()
+            .filter(|(_, lit)| **lit)
+            .map(|(idx, _)| Position::new(idx.i as i128 - 100, idx.j as i128 + 7))
+            .collect_vec();
+        assert_eq!(read_positions(&lit), Ok("OK".to_string()));
+    }
+
+    #[test]
+    fn unknown_glyph() {
+        // A, a vertical bar and B
+        let rows = (0..6)
+            .map(|j| format!("{}.#.{}", SMALL_FONT[0].1[j], SMALL_FONT[1].1[j]))
+            .collect_vec();
+        let err = read_grid(&screen(&rows)).unwrap_err();
+        assert_eq!(err.text, "A?B");
+        assert_eq!(err.unknown, vec![Grid::empty(Size::new(1, 6), true)]);
+        assert!(err.to_string().starts_with("Unknown glyphs in 'A?B':"));
+    }
+
+    #[test]
+    fn unknown_height() {
+        let err = read_grid(&Grid::empty(Size::new(3, 4), true)).unwrap_err();
+        assert_eq!(err.text, "?");
+        assert_eq!(err.unknown, vec![Grid::empty(Size::new(3, 4), true)]);
+    }
+}
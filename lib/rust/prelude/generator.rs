@@ -0,0 +1,85 @@
+use std::ops::Range;
+
+use crate::prelude::types::{ProblemInput, ProblemResult};
+
+/// Small deterministic PRNG (SplitMix64), so that generated inputs are reproducible from their seed
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        SeededRng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0, n)
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0);
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Uniform value in the given (non-empty) range
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end);
+        range
+            .start
+            .wrapping_add(self.below(range.start.abs_diff(range.end)) as i64)
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let sample = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        sample < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// Creates random valid inputs for a day
+pub trait InputGenerator {
+    fn generate(&self, rng: &mut SeededRng) -> Vec<String>;
+
+    fn input(&self, seed: u64) -> ProblemInput {
+        self.generate(&mut SeededRng::new(seed)).into_iter().collect()
+    }
+}
+
+/// Compares two implementations on the inputs generated for all seeds, fails with the first differing input
+pub fn cross_check<G, F, R>(generator: &G, seeds: Range<u64>, implementation: F, reference: R) -> Result<(), String>
+where
+    G: InputGenerator + ?Sized,
+    F: Fn(ProblemInput) -> ProblemResult,
+    R: Fn(ProblemInput) -> ProblemResult,
+{
+    for seed in seeds {
+        let lines = generator.generate(&mut SeededRng::new(seed));
+        let result = implementation(lines.iter().cloned().collect());
+        let expected = reference(lines.iter().cloned().collect());
+
+        if result != expected {
+            return Err(format!(
+                "Seed {seed}: got {result:?}, expected {expected:?}, input:\n{}",
+                lines.join("\n")
+            ));
+        }
+    }
+
+    Ok(())
+}
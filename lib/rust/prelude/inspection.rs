@@ -0,0 +1,144 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use itertools::Itertools;
+use regex::Regex;
+
+/// Irregularities of a raw input file, which hint at a wrongly saved input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputIssue {
+    Empty,
+    /// Number of lines ending in CRLF (normalized on read)
+    CrLf(usize),
+    NoTrailingNewline,
+    /// Number of empty lines at the end of the file
+    TrailingEmptyLines(usize),
+    /// Line numbers (1-based) with trailing whitespace
+    TrailingWhitespace(Vec<usize>),
+}
+
+impl InputIssue {
+    /// Detects all issues in the raw file content
+    pub fn detect(raw: &str, lines: &[String]) -> Vec<InputIssue> {
+        let mut issues = Vec::new();
+        if raw.is_empty() {
+            issues.push(InputIssue::Empty);
+            return issues;
+        }
+
+        let crlf = raw.matches("\r\n").count();
+        if crlf > 0 {
+            issues.push(InputIssue::CrLf(crlf));
+        }
+
+        if !raw.ends_with('\n') {
+            issues.push(InputIssue::NoTrailingNewline);
+        }
+
+        let empty = lines.iter().rev().take_while(|l| l.is_empty()).count();
+        if empty > 0 {
+            issues.push(InputIssue::TrailingEmptyLines(empty));
+        }
+
+        let whitespace = lines
+            .iter()
+            .positions(|l| l.ends_with(char::is_whitespace))
+            .map(|i| i + 1)
+            .collect_vec();
+        if !whitespace.is_empty() {
+            issues.push(InputIssue::TrailingWhitespace(whitespace));
+        }
+
+        issues
+    }
+}
+
+impl Display for InputIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputIssue::Empty => write!(f, "File is empty"),
+            InputIssue::CrLf(n) => write!(f, "{n} lines end with CRLF (normalized)"),
+            InputIssue::NoTrailingNewline => write!(f, "Last line has no trailing newline, might be truncated"),
+            InputIssue::TrailingEmptyLines(n) => write!(f, "{n} empty lines at the end"),
+            InputIssue::TrailingWhitespace(lines) => {
+                write!(f, "Trailing whitespace in lines {}", lines.iter().join(", "))
+            }
+        }
+    }
+}
+
+/// Summary of the shape of an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputShape {
+    pub lines: usize,
+    pub min_width: usize,
+    pub max_width: usize,
+    pub charset: BTreeSet<char>,
+    /// Count, min and max of all integers in the input (if any)
+    pub numbers: Option<(usize, i128, i128)>,
+    pub issues: Vec<InputIssue>,
+}
+
+impl InputShape {
+    pub fn new(lines: &[String], issues: &[InputIssue]) -> Self {
+        let (min_width, max_width) = lines
+            .iter()
+            .map(|l| l.chars().count())
+            .minmax()
+            .into_option()
+            .unwrap_or((0, 0));
+
+        // A minus directly after a digit is a range like 1-3, not a sign
+        let number_regex = Regex::new(r"(?:^|[^\d])(-?\d+)").unwrap();
+        let numbers = lines
+            .iter()
+            .flat_map(|l| number_regex.captures_iter(l))
+            .filter_map(|c| c[1].parse::<i128>().ok())
+            .collect_vec();
+        let numbers = numbers
+            .iter()
+            .minmax()
+            .into_option()
+            .map(|(min, max)| (numbers.len(), *min, *max));
+
+        InputShape {
+            lines: lines.len(),
+            min_width,
+            max_width,
+            charset: lines.iter().flat_map(|l| l.chars()).collect(),
+            numbers,
+            issues: issues.to_vec(),
+        }
+    }
+
+    /// All lines have the same width
+    pub fn is_grid(&self) -> bool {
+        self.lines > 0 && self.min_width == self.max_width
+    }
+}
+
+impl Display for InputShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Lines:    {}", self.lines)?;
+        if self.is_grid() {
+            writeln!(
+                f,
+                "Width:    {} (grid {}x{})",
+                self.max_width, self.max_width, self.lines
+            )?;
+        } else {
+            writeln!(f, "Width:    {} to {}", self.min_width, self.max_width)?;
+        }
+        writeln!(f, "Charset:  {:?}", self.charset.iter().collect::<String>())?;
+        match self.numbers {
+            Some((count, min, max)) => writeln!(f, "Numbers:  {count} in range {min} to {max}")?,
+            None => writeln!(f, "Numbers:  none")?,
+        }
+
+        if self.issues.is_empty() {
+            write!(f, "Issues:   none")
+        } else {
+            write!(f, "Issues:")?;
+            self.issues.iter().try_for_each(|issue| write!(f, "\n  - {issue}"))
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod generator;
+pub mod inspection;
 pub mod runner;
 pub mod solution;
 pub mod testing;
@@ -1,8 +1,9 @@
-use std::{path::Path, time::Duration};
+use std::{ops::Range, path::Path, time::Duration};
 
 use crate::prelude::{
+    generator::{self, SeededRng},
     solution::Solution,
-    types::{ProblemInput, ProblemResult},
+    types::{ProblemInput, ProblemResult, StreamedInput},
 };
 
 use clap::{Args, Parser, Subcommand};
@@ -26,6 +27,10 @@ pub enum Commands {
     Main,
     /// Run a specific day
     Day(DayArgs),
+    /// Describe the shape of a day's input and report irregularities
+    Inspect(InspectArgs),
+    /// Print a random input from the day's generator
+    Generate(GenerateArgs),
 }
 
 #[derive(Args)]
@@ -44,12 +49,46 @@ pub struct DayArgs {
     /// Run both parts (overrides --part)
     #[arg(short, long)]
     pub all: bool,
+
+    /// Use a custom input file instead (--sample is still passed to the solution)
+    #[arg(short, long)]
+    pub input: Option<String>,
+
+    /// Read the input lazily line by line
+    #[arg(long)]
+    pub stream: bool,
+}
+
+#[derive(Args)]
+pub struct InspectArgs {
+    /// Day number (1-25)
+    pub day: u8,
+
+    /// Use sample input instead of real input
+    #[arg(short, long)]
+    pub sample: bool,
+
+    /// Part, only relevant if the inputs differ per part
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day number (1-25)
+    pub day: u8,
+
+    /// Seed for the random generator
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
 }
 
 pub struct RunConfig {
     pub all: bool,
     pub part: u8,
     pub use_sample: bool,
+    pub input: Option<String>,
+    pub stream: bool,
 }
 
 impl AocRunner {
@@ -81,12 +120,35 @@ impl AocRunner {
                     }
                 };
 
-                if config.all {
+                if config.input.is_some() || config.stream {
+                    self.run_custom(day, &config);
+                } else if config.all {
                     self.run_day(day);
                 } else {
                     self.run_single(day, config.part, config.use_sample);
                 }
             }
+            Commands::Inspect(args) => {
+                if !(1..=25).contains(&args.day) {
+                    eprintln!("Error: Day must be between 1 and 25, got: {}", args.day);
+                    std::process::exit(1);
+                }
+
+                self.inspect(args.day, args.part, args.sample);
+            }
+            Commands::Generate(args) => {
+                let day = args.day as usize;
+                match self.solutions.get(day.wrapping_sub(1)).and_then(|s| s.generator()) {
+                    Some(generator) => {
+                        let lines = generator.generate(&mut SeededRng::new(args.seed));
+                        println!("{}", lines.join("\n"));
+                    }
+                    None => {
+                        eprintln!("No generator implemented for day {:02} in year {}", day, self.year);
+                        std::process::exit(1);
+                    }
+                }
+            }
         }
     }
 
@@ -97,6 +159,8 @@ impl AocRunner {
                 all: true,
                 part: 1, // dummy value, not used
                 use_sample: args.sample,
+                input: args.input.clone(),
+                stream: args.stream,
             })
         } else if let Some(part) = args.part {
             // --part is specified
@@ -104,6 +168,8 @@ impl AocRunner {
                 all: false,
                 part,
                 use_sample: args.sample,
+                input: args.input.clone(),
+                stream: args.stream,
             })
         } else {
             Err("--part <1|2> is required unless --all is given".to_string())
@@ -143,6 +209,39 @@ impl AocRunner {
         }
     }
 
+    /// Like `verify_solution`, but the input is streamed into the solution.
+    /// Results are compared by value, as streamed versions may use wider types for large inputs.
+    pub fn verify_streamed_solution(&self, day: usize, version: u8, use_sample: bool) -> Result<(), String> {
+        let solution = &self.solutions[day - 1];
+        assert!(version == 1 || version == 2);
+        let expected = &solution.results()[(version - 1) as usize * 2 + (!use_sample as usize)];
+        let result = match StreamedInput::open(&self.input_filename(day as u8, version, use_sample)) {
+            Some(input) => solution.solve_streamed(input, version, use_sample).0,
+            None => ProblemResult::NoInput,
+        };
+
+        if result.to_string() != expected.to_string() {
+            Err(format!("Got {:?}, expected {:?}", result, expected))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Compares solution and reference implementation of a day on generated inputs
+    pub fn cross_check(&self, day: usize, version: u8, seeds: Range<u64>) -> Result<(), String> {
+        let solution = &self.solutions[day - 1];
+        let generator = solution
+            .generator()
+            .ok_or(format!("No generator implemented for day {day:02}"))?;
+
+        generator::cross_check(
+            generator,
+            seeds,
+            |input| solution.solve(input, version, false).0,
+            |input| solution.reference(input, version),
+        )
+    }
+
     fn run_full_year(&self) {
         let mut total_elapsed = Duration::ZERO;
         for day in 0..self.solutions.len() {
@@ -193,14 +292,49 @@ impl AocRunner {
         );
     }
 
-    fn get_input(&self, day: u8, version: u8, use_sample: bool) -> Option<ProblemInput> {
+    fn run_custom(&self, day: usize, config: &RunConfig) {
+        let solution = &self.solutions[day - 1];
+        let versions = if config.all { vec![1, 2] } else { vec![config.part] };
+        for version in versions {
+            let filename = match &config.input {
+                Some(filename) => filename.clone(),
+                None => self.input_filename(day as u8, version, config.use_sample),
+            };
+
+            let (result, elapsed) = match config.stream {
+                true => StreamedInput::open(&filename)
+                    .map(|input| solution.solve_streamed(input, version, config.use_sample)),
+                false => ProblemInput::read(&filename).map(|input| solution.solve(input, version, config.use_sample)),
+            }
+            .unwrap_or((ProblemResult::NoInput, Duration::ZERO));
+
+            println!("Day {day:02} / Version {version} / File '{filename}' => {elapsed:?}\n{result}");
+        }
+    }
+
+    fn inspect(&self, day: u8, version: u8, use_sample: bool) {
+        match self.get_input(day, version, use_sample) {
+            Some(input) => println!(
+                "Day {day:02} / Version {version} / Data '{}'\n{}",
+                Self::SAMPLE_STR[use_sample as usize],
+                input.shape()
+            ),
+            None => println!("No input available for day {day:02} in year {0}", self.year),
+        }
+    }
+
+    fn input_filename(&self, day: u8, version: u8, use_sample: bool) -> String {
         let base_filename = if use_sample { "sample" } else { "input" };
         let mut fullname = format!("{0}\\inputs\\{base_filename}{day:02}.txt", self.year);
         if !Path::new(&fullname).exists() {
             fullname = fullname.replace(".txt", format!("_{version}.txt").as_str());
         }
 
-        ProblemInput::read(&fullname)
+        fullname
+    }
+
+    fn get_input(&self, day: u8, version: u8, use_sample: bool) -> Option<ProblemInput> {
+        ProblemInput::read(&self.input_filename(day, version, use_sample))
     }
 
     fn get_result(&self, day: usize, version: u8, use_sample: bool) -> (ProblemResult, Duration) {
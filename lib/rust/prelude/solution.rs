@@ -1,6 +1,7 @@
 use std::time::{Duration, Instant};
 
-use crate::prelude::types::{ProblemInput, ProblemResult};
+use crate::prelude::generator::InputGenerator;
+use crate::prelude::types::{ProblemInput, ProblemResult, StreamedInput};
 
 pub trait Solution {
     fn solve(&self, data: ProblemInput, version: u8, is_sample: bool) -> (ProblemResult, Duration) {
@@ -13,7 +14,37 @@ pub trait Solution {
         (result, now.elapsed())
     }
 
+    fn solve_streamed(&self, data: StreamedInput, version: u8, is_sample: bool) -> (ProblemResult, Duration) {
+        let now = Instant::now();
+        let result = match version {
+            1 => self.solve_version01_streamed(data, is_sample),
+            2 => self.solve_version02_streamed(data, is_sample),
+            _ => panic!("Invalid Version Number '{version}'"),
+        };
+        (result, now.elapsed())
+    }
+
     fn results(&self) -> [ProblemResult; 4];
     fn solve_version01(&self, input: ProblemInput, is_sample: bool) -> ProblemResult;
     fn solve_version02(&self, input: ProblemInput, is_sample: bool) -> ProblemResult;
+
+    /// Override to process the input lazily, by default the input is collected first
+    fn solve_version01_streamed(&self, input: StreamedInput, is_sample: bool) -> ProblemResult {
+        self.solve_version01(input.collect(), is_sample)
+    }
+
+    /// Override to process the input lazily, by default the input is collected first
+    fn solve_version02_streamed(&self, input: StreamedInput, is_sample: bool) -> ProblemResult {
+        self.solve_version02(input.collect(), is_sample)
+    }
+
+    /// Generator for random valid inputs, if the day provides one
+    fn generator(&self) -> Option<&dyn InputGenerator> {
+        None
+    }
+
+    /// Simpler (e.g. brute-force) implementation, which generated inputs are cross-checked against
+    fn reference(&self, _input: ProblemInput, _version: u8) -> ProblemResult {
+        ProblemResult::Unsolved
+    }
 }
@@ -33,3 +33,53 @@ macro_rules! test_version {
         )*
     };
 }
+
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! test_generated {
+    ($day:ident, $seeds:expr; $($version:ident),+) => {
+        mod $day {
+            $(
+                #[test]
+                fn $version() -> Result<(), String> {
+                    let runner = crate::create_runner();
+                    runner.cross_check(
+                        stringify!($day).trim_start_matches("day").parse().unwrap(),
+                        stringify!($version).trim_start_matches("version").parse().unwrap(),
+                        $seeds
+                    )
+                }
+            )+
+        }
+    };
+}
+
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! test_streamed {
+    ($day:ident; $($version:ident),+) => {
+        mod $day {
+            $(
+                mod $version {
+                    #[test]
+                    fn sample() -> Result<(), String> {
+                        crate::create_runner().verify_streamed_solution(
+                            stringify!($day).trim_start_matches("day").parse().unwrap(),
+                            stringify!($version).trim_start_matches("version").parse().unwrap(),
+                            true
+                        )
+                    }
+
+                    #[test]
+                    fn real() -> Result<(), String> {
+                        crate::create_runner().verify_streamed_solution(
+                            stringify!($day).trim_start_matches("day").parse().unwrap(),
+                            stringify!($version).trim_start_matches("version").parse().unwrap(),
+                            false
+                        )
+                    }
+                }
+            )+
+        }
+    };
+}
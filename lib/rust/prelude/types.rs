@@ -1,25 +1,45 @@
 use std::{
     fmt::Display,
-    fs::File,
-    io::{BufRead, BufReader},
+    fs::{self, File},
+    io::{BufRead, BufReader, Lines},
 };
 
 use itertools::Itertools;
 use num::bigint::BigInt;
 
-use crate::cartesian::Grid;
+use crate::cartesian::{Grid, GridCell};
+use crate::prelude::inspection::{InputIssue, InputShape};
 
 pub struct ProblemInput {
     lines: Vec<String>,
+    issues: Vec<InputIssue>,
 }
 
 impl ProblemInput {
     pub fn read(filename: &str) -> Option<Self> {
-        let file = File::open(filename).ok()?;
-        let buf = BufReader::new(file);
+        let raw = fs::read_to_string(filename).ok()?;
+        Some(Self::from_raw(&raw))
+    }
+
+    /// Splits raw content into lines, CRLF line endings are normalized
+    pub fn from_raw(raw: &str) -> Self {
+        let content = raw.replace("\r\n", "\n");
+        let content = content.strip_suffix('\n').unwrap_or(&content);
+        let lines = match raw.is_empty() {
+            true => vec![],
+            false => content.split('\n').map(String::from).collect_vec(),
+        };
+
+        let issues = InputIssue::detect(raw, &lines);
+        ProblemInput { lines, issues }
+    }
+
+    pub fn issues(&self) -> &[InputIssue] {
+        &self.issues
+    }
 
-        let lines = buf.lines().map(|l| l.expect("Could not parse line")).collect();
-        Some(ProblemInput { lines })
+    pub fn shape(&self) -> InputShape {
+        InputShape::new(&self.lines, &self.issues)
     }
 
     pub fn lines(&self) -> Vec<String> {
@@ -30,8 +50,67 @@ impl ProblemInput {
         self.lines.join("")
     }
 
+    /// Lines shorter than the longest one are padded with spaces
     pub fn grid(&self) -> Grid<char> {
-        Grid::new(self.lines.iter().map(|row| row.chars().collect()).collect())
+        self.padded_grid_of(' ')
+    }
+
+    /// All lines need to have the same length
+    pub fn grid_of<T: GridCell>(&self) -> Grid<T> {
+        Grid::new(
+            self.lines
+                .iter()
+                .map(|row| row.chars().map(T::from_char).collect())
+                .collect(),
+        )
+    }
+
+    /// Lines shorter than the longest one are padded with the fill value
+    pub fn padded_grid_of<T: GridCell + Clone>(&self, fill: T) -> Grid<T> {
+        let width = self.lines.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+        Grid::new(
+            self.lines
+                .iter()
+                .map(|row| {
+                    row.chars()
+                        .map(T::from_char)
+                        .pad_using(width, |_| fill.clone())
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl FromIterator<String> for ProblemInput {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        ProblemInput {
+            lines: iter.into_iter().collect(),
+            issues: vec![],
+        }
+    }
+}
+
+/// Input which is read lazily line by line, meant for very large (generated) inputs.
+/// Line endings are normalized, but no issues are detected.
+pub struct StreamedInput {
+    lines: Lines<BufReader<File>>,
+}
+
+impl StreamedInput {
+    pub fn open(filename: &str) -> Option<Self> {
+        let file = File::open(filename).ok()?;
+        Some(StreamedInput {
+            lines: BufReader::new(file).lines(),
+        })
+    }
+}
+
+impl Iterator for StreamedInput {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next().map(|l| l.expect("Could not read line"))
     }
 }
 
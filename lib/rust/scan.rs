@@ -0,0 +1,317 @@
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+/// Matches an input line against a pattern, in which each `{}` marks a field to be parsed.
+///
+/// Single pattern, returns the parsed value (one type) or a tuple (multiple types):
+/// `scan!(line, "rotate row y={} by {}" => usize, usize)`
+///
+/// Alternative patterns, returns the first matching variant:
+/// `scan!(line; "rect {}x{}" => Inst::Rect(usize, usize), "noop" => Inst::Noop)`
+///
+/// Evaluates to `Result<_, ScanError>`. A field extends up until the next literal part of the pattern,
+/// hence two fields must be separated by some literal.
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal) => {{
+        let input: &str = AsRef::<str>::as_ref(&$input);
+        $crate::scan::Scanner::new($pattern, input).and_then(|scanner| scanner.finish())
+    }};
+    ($input:expr, $pattern:literal => $t:ty) => {{
+        let input: &str = AsRef::<str>::as_ref(&$input);
+        (|| -> Result<$t, $crate::scan::ScanError> {
+            let mut scanner = $crate::scan::Scanner::new($pattern, input)?;
+            let value = scanner.field::<$t>()?;
+            scanner.finish()?;
+            Ok(value)
+        })()
+    }};
+    ($input:expr, $pattern:literal => $($t:ty),+ $(,)?) => {{
+        let input: &str = AsRef::<str>::as_ref(&$input);
+        (|| -> Result<($($t,)+), $crate::scan::ScanError> {
+            let mut scanner = $crate::scan::Scanner::new($pattern, input)?;
+            let values = ($(scanner.field::<$t>()?,)+);
+            scanner.finish()?;
+            Ok(values)
+        })()
+    }};
+    ($input:expr; $($pattern:literal => $($variant:ident)::+ $(($($t:ty),* $(,)?))?),+ $(,)?) => {{
+        let input: &str = AsRef::<str>::as_ref(&$input);
+        (|| -> Result<_, $crate::scan::ScanError> {
+            let mut attempts = Vec::new();
+            $(
+                let attempt = (|| -> Result<_, $crate::scan::ScanError> {
+                    #[allow(unused_mut)]
+                    let mut scanner = $crate::scan::Scanner::new($pattern, input)?;
+                    let value = $($variant)::+ $(($(scanner.field::<$t>()?),*))?;
+                    scanner.finish()?;
+                    Ok(value)
+                })();
+                match attempt {
+                    Ok(value) => return Ok(value),
+                    Err(err) => attempts.push(err),
+                }
+            )+
+            Err($crate::scan::ScanError::NoMatch {
+                input: input.to_string(),
+                attempts,
+            })
+        })()
+    }};
+}
+
+/// Reason why a line didn't match a pattern, positions are byte offsets into the input
+#[derive(Clone, PartialEq, Eq)]
+pub enum ScanError {
+    Mismatch {
+        pattern: String,
+        input: String,
+        position: usize,
+        expected: String,
+    },
+    Parse {
+        pattern: String,
+        input: String,
+        position: usize,
+        field: usize,
+        text: String,
+        target: &'static str,
+    },
+    TrailingInput {
+        pattern: String,
+        input: String,
+        position: usize,
+    },
+    NoMatch {
+        input: String,
+        attempts: Vec<ScanError>,
+    },
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScanError::Mismatch {
+                pattern,
+                input,
+                position,
+                expected,
+            } => write!(f, "'{pattern}' on '{input}' at {position}: expected '{expected}'"),
+            ScanError::Parse {
+                pattern,
+                input,
+                position,
+                field,
+                text,
+                target,
+            } => write!(
+                f,
+                "'{pattern}' on '{input}' at {position}: field {field} '{text}' is no valid {target}"
+            ),
+            ScanError::TrailingInput {
+                pattern,
+                input,
+                position,
+            } => write!(f, "'{pattern}' on '{input}' at {position}: unexpected trailing input"),
+            ScanError::NoMatch { input, attempts } => {
+                write!(f, "no pattern matches '{input}'")?;
+                attempts.iter().try_for_each(|err| write!(f, "\n  {err}"))
+            }
+        }
+    }
+}
+
+// Delegate to display, so that unwrapping a failed scan is readable
+impl Debug for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+/// Matches the pattern step by step, used by the `scan!` macro
+pub struct Scanner<'a> {
+    pattern: &'a str,
+    input: &'a str,
+    literals: Vec<&'a str>,
+    field: usize,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(pattern: &'a str, input: &'a str) -> Result<Self, ScanError> {
+        let literals = pattern.split("{}").collect_vec();
+        assert!(
+            literals.iter().skip(1).dropping_back(1).all(|lit| !lit.is_empty()),
+            "Fields in pattern '{pattern}' need to be separated by a literal"
+        );
+
+        let mut scanner = Scanner {
+            pattern,
+            input,
+            literals,
+            field: 0,
+            position: 0,
+        };
+
+        let prefix = scanner.literals[0];
+        if !input.starts_with(prefix) {
+            return Err(scanner.mismatch(prefix));
+        }
+        scanner.position = prefix.len();
+        Ok(scanner)
+    }
+
+    /// Parses the next field
+    pub fn field<T: FromStr>(&mut self) -> Result<T, ScanError> {
+        assert!(
+            self.field + 1 < self.literals.len(),
+            "Pattern '{}' has only {} fields",
+            self.pattern,
+            self.literals.len() - 1
+        );
+
+        let rest = &self.input[self.position..];
+        let terminator = self.literals[self.field + 1];
+        let end = if terminator.is_empty() {
+            rest.len()
+        } else {
+            rest.find(terminator).ok_or_else(|| self.mismatch(terminator))?
+        };
+
+        let text = &rest[..end];
+        let value = text.parse().map_err(|_| ScanError::Parse {
+            pattern: self.pattern.to_string(),
+            input: self.input.to_string(),
+            position: self.position,
+            field: self.field,
+            text: text.to_string(),
+            target: std::any::type_name::<T>(),
+        })?;
+
+        self.position += end + terminator.len();
+        self.field += 1;
+        Ok(value)
+    }
+
+    /// Verifies that the whole input has been consumed
+    pub fn finish(self) -> Result<(), ScanError> {
+        assert_eq!(
+            self.field,
+            self.literals.len() - 1,
+            "Not all fields of pattern '{}' have been parsed",
+            self.pattern
+        );
+
+        if self.position < self.input.len() {
+            return Err(ScanError::TrailingInput {
+                pattern: self.pattern.to_string(),
+                input: self.input.to_string(),
+                position: self.position,
+            });
+        }
+        Ok(())
+    }
+
+    fn mismatch(&self, expected: &str) -> ScanError {
+        ScanError::Mismatch {
+            pattern: self.pattern.to_string(),
+            input: self.input.to_string(),
+            position: self.position,
+            expected: expected.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Instruction {
+        Rect(usize, usize),
+        Rotate(char, i32),
+        Noop,
+    }
+
+    fn instruction(line: &str) -> Result<Instruction, ScanError> {
+        scan!(line;
+            "rect {}x{}" => Instruction::Rect(usize, usize),
+            "rotate {} by {}" => Instruction::Rotate(char, i32),
+            "noop" => Instruction::Noop,
+        )
+    }
+
+    #[test]
+    fn single_field() {
+        assert_eq!(scan!("x=-12", "x={}" => i32), Ok(-12));
+        assert_eq!(scan!("done", "done"), Ok(()));
+    }
+
+    #[test]
+    fn tuple() {
+        assert_eq!(
+            scan!("Sensor at x=2, y=-18: tag", "Sensor at x={}, y={}: {}" => i64, i64, String),
+            Ok((2, -18, "tag".to_string()))
+        );
+    }
+
+    #[test]
+    fn alternatives() {
+        assert_eq!(instruction("rect 3x2"), Ok(Instruction::Rect(3, 2)));
+        assert_eq!(instruction("rotate y by -4"), Ok(Instruction::Rotate('y', -4)));
+        assert_eq!(instruction("noop"), Ok(Instruction::Noop));
+    }
+
+    #[test]
+    fn mismatch() {
+        let err = scan!("x=1 y=2", "x={}, y={}" => i32, i32).unwrap_err();
+        assert_eq!(
+            err,
+            ScanError::Mismatch {
+                pattern: "x={}, y={}".to_string(),
+                input: "x=1 y=2".to_string(),
+                position: 2,
+                expected: ", y=".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = scan!("x=a", "x={}" => i32).unwrap_err();
+        assert!(matches!(err, ScanError::Parse { position: 2, field: 0, ref text, .. } if text == "a"));
+    }
+
+    #[test]
+    fn trailing_input() {
+        let err = scan!("noop now", "noop").unwrap_err();
+        assert_eq!(
+            err,
+            ScanError::TrailingInput {
+                pattern: "noop".to_string(),
+                input: "noop now".to_string(),
+                position: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn no_match() {
+        let err = instruction("rect 3by2").unwrap_err();
+        let ScanError::NoMatch { input, attempts } = &err else {
+            panic!("Expected NoMatch, got {err}");
+        };
+        assert_eq!(input, "rect 3by2");
+        assert_eq!(attempts.len(), 3);
+        assert!(matches!(attempts[0], ScanError::Mismatch { position: 5, .. }));
+        assert!(err
+            .to_string()
+            .starts_with("no pattern matches 'rect 3by2'\n  'rect {}x{}'"));
+    }
+}